@@ -1,42 +1,267 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path, sync::OnceLock};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
     pub module_name: String,
-    pub generate: String,
+    pub generate: Generate,
     pub hydratable: bool,
     pub delegate_events: bool,
     pub delegated_events: Vec<String>,
+    pub non_delegated_events: Vec<String>,
     pub built_ins: Vec<String>,
-    pub require_import_source: bool,
+    pub require_import_source: RequireImportSource,
     pub wrap_conditionals: bool,
     pub omit_nested_closing_tags: bool,
+    pub whitespace_sensitive_tags: Vec<String>,
     pub context_to_custom_elements: bool,
     pub static_marker: String,
     pub effect_wrapper: String,
     pub memo_wrapper: String,
     pub validate: bool,
+    pub renderers: Vec<RendererConfig>,
+    pub dev: bool,
+    pub hmr: Option<Hmr>,
+    pub hmr_module_name: String,
+    pub stable_ids: bool,
+    pub shared_templates: String,
+    pub minify_templates: bool,
+    pub helper_imports: HashMap<String, HelperImport>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub static_functions: Vec<String>,
+    pub static_modules: Vec<String>,
+    #[serde(skip)]
+    pub globs: GlobCache,
+}
+
+impl Config {
+    /// Parses the plugin options, rejecting unknown keys and values so typos
+    /// fail the build instead of silently falling back to the defaults.
+    pub fn from_json(json: &str) -> Result<Config, String> {
+        let config: Config = serde_json::from_str(json)
+            .map_err(|err| format!("Invalid jsx-dom-expressions config: {err}"))?;
+        if let (Some(hmr), false) = (config.hmr, config.dev) {
+            return Err(format!(
+                "Invalid jsx-dom-expressions config: `hmr: \"{}\"` requires `dev: true`",
+                hmr.as_str()
+            ));
+        }
+        let globs = GlobSets::new(&config)?;
+        let _ = config.globs.0.set(globs);
+        Ok(config)
+    }
+
+    /// The compiled `include`, `exclude` and `staticModules` globs. Configs not
+    /// parsed by `from_json` compile them on first use and panic on invalid
+    /// patterns, like `from_json` errors do in the plugin.
+    fn glob_sets(&self) -> &GlobSets {
+        self.globs
+            .0
+            .get_or_init(|| GlobSets::new(self).unwrap_or_else(|err| panic!("{err}")))
+    }
+
+    /// Whether `filename` passes the `include` and `exclude` globs. With no
+    /// `include` every file not excluded is transformed.
+    pub fn should_transform(&self, filename: &str) -> bool {
+        let filename = filename.replace('\\', "/");
+        let globs = self.glob_sets();
+        (self.include.is_empty() || globs.include.is_match(&filename))
+            && !globs.exclude.is_match(&filename)
+    }
+
+    /// `should_transform` for a path the bundler passed, which is matched
+    /// relative to `cwd` when it lies inside it as globs name project files.
+    pub fn should_transform_in(&self, filename: &str, cwd: Option<&str>) -> bool {
+        let relative = cwd.and_then(|cwd| Path::new(filename).strip_prefix(cwd).ok());
+        match relative {
+            Some(relative) => self.should_transform(&relative.to_string_lossy()),
+            None => self.should_transform(filename),
+        }
+    }
+
+    /// Whether values imported from `source` match the `staticModules` globs
+    /// and so never need tracking.
+    pub fn is_static_module(&self, source: &str) -> bool {
+        self.glob_sets().static_modules.is_match(source)
+    }
+}
+
+/// Lazily compiled globs of a `Config`, so files are matched without
+/// rebuilding the sets on every call.
+#[derive(Clone, Default)]
+pub struct GlobCache(OnceLock<GlobSets>);
+
+#[derive(Clone)]
+struct GlobSets {
+    include: GlobSet,
+    exclude: GlobSet,
+    static_modules: GlobSet,
+}
+
+impl GlobSets {
+    fn new(config: &Config) -> Result<GlobSets, String> {
+        Ok(GlobSets {
+            include: build_glob_set(&config.include)?,
+            exclude: build_glob_set(&config.exclude)?,
+            static_modules: build_glob_set(&config.static_modules)?,
+        })
+    }
+}
+
+/// Builds the set with `*` kept within one path segment, so only `**`
+/// reaches into nested directories.
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(
+            GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|err| format!("Invalid jsx-dom-expressions config: {err}"))?,
+        );
+    }
+    builder
+        .build()
+        .map_err(|err| format!("Invalid jsx-dom-expressions config: {err}"))
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             module_name: "solid-js/web".to_owned(),
-            generate: "dom".to_owned(),
+            generate: Generate::Dom,
             hydratable: false,
             delegate_events: true,
             delegated_events: vec![],
+            non_delegated_events: vec![],
             built_ins: vec![],
-            require_import_source: false,
+            require_import_source: RequireImportSource::Enabled(false),
             wrap_conditionals: true,
             omit_nested_closing_tags: false,
+            whitespace_sensitive_tags: vec!["pre".to_owned(), "textarea".to_owned()],
             context_to_custom_elements: false,
             static_marker: "@once".to_owned(),
             effect_wrapper: "effect".to_owned(),
             memo_wrapper: "memo".to_owned(),
             validate: true,
+            renderers: vec![],
+            dev: false,
+            hmr: None,
+            hmr_module_name: "solid-refresh".to_owned(),
+            stable_ids: false,
+            shared_templates: "".to_owned(),
+            minify_templates: false,
+            helper_imports: HashMap::new(),
+            include: vec![],
+            exclude: vec![],
+            static_functions: vec![],
+            static_modules: vec![],
+            globs: GlobCache::default(),
+        }
+    }
+}
+
+/// Output flavour: client DOM code, server rendered strings, calls into a
+/// custom renderer, or DOM and custom renderers chosen per element.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Generate {
+    Dom,
+    Ssr,
+    Universal,
+    Dynamic,
+}
+
+/// Bundler whose hot module replacement API the `solid-refresh` glue targets.
+/// Only honoured together with `dev`, which `Config::from_json` enforces.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hmr {
+    Esm,
+    Standard,
+    Vite,
+    Webpack5,
+    RspackEsm,
+}
+
+impl Hmr {
+    /// The bundler name `$$refresh` expects.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Hmr::Esm => "esm",
+            Hmr::Standard => "standard",
+            Hmr::Vite => "vite",
+            Hmr::Webpack5 => "webpack5",
+            Hmr::RspackEsm => "rspack-esm",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[serde(default, deny_unknown_fields)]
+pub struct RendererConfig {
+    pub name: String,
+    pub module_name: String,
+    pub elements: Vec<String>,
+}
+
+/// `requireImportSource` accepts either a boolean, which checks the
+/// `@jsxImportSource` pragma against `moduleName`, or the expected source.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RequireImportSource {
+    Enabled(bool),
+    Source(String),
+}
+
+impl RequireImportSource {
+    pub fn source<'a>(&'a self, module_name: &'a str) -> Option<&'a str> {
+        match self {
+            RequireImportSource::Enabled(true) => Some(module_name),
+            RequireImportSource::Enabled(false) => None,
+            RequireImportSource::Source(source) => Some(source),
+        }
+    }
+}
+
+/// Where a runtime helper such as `effect` or `insert` is imported from when
+/// it shouldn't come from `moduleName`: either just the module, or the module
+/// and the name it is exported under.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum HelperImport {
+    Module(String),
+    Named(NamedHelperImport),
+}
+
+/// The `{ moduleName, importName }` form of a `HelperImport`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NamedHelperImport {
+    pub module_name: String,
+    pub import_name: Option<String>,
+}
+
+impl HelperImport {
+    pub fn module_name(&self) -> &str {
+        match self {
+            HelperImport::Module(module_name)
+            | HelperImport::Named(NamedHelperImport { module_name, .. }) => module_name,
+        }
+    }
+
+    pub fn import_name<'a>(&'a self, helper: &'a str) -> &'a str {
+        match self {
+            HelperImport::Named(NamedHelperImport {
+                import_name: Some(import_name),
+                ..
+            }) => import_name,
+            _ => helper,
         }
     }
 }
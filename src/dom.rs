@@ -1,3 +1,4 @@
 pub mod constants;
 pub mod element;
+pub mod minify;
 pub mod template;
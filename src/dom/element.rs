@@ -1,27 +1,29 @@
 use crate::{
     TransformVisitor,
+    config::Generate,
     shared::{
         constants::{
-            ALIASES, CHILD_PROPERTIES, DELEGATED_EVENTS, PROPERTIES, SVG_ELEMENTS, SVGNAMESPACE,
-            VOID_ELEMENTS, get_prop_alias,
+            ALIASES, BOOLEANS, CHILD_PROPERTIES, DELEGATED_EVENTS, PROPERTIES, SVG_ELEMENTS,
+            SVGNAMESPACE, VOID_ELEMENTS, get_prop_alias,
         },
+        evaluate::{EvalResult, is_truthy},
         structs::{DynamicAttr, ProcessSpreadsInfo, TemplateInstantiation},
         transform::{TransformInfo, is_component},
         utils::{
             IntoFirst, RESERVED_NAME_SPACES, can_native_spread, check_length,
-            convert_jsx_identifier, escape_backticks, escape_html, filter_children, get_tag_name,
-            is_l_val, is_static_expr, lit_to_string, make_getter_prop, make_jsx_attr_expr,
-            make_member_assign, make_var_declarator, to_property_name, trim_whitespace,
-            unwrap_ts_expr,
+            convert_jsx_identifier, drops_leading_newline, emit_error, escape_backticks,
+            escape_html, filter_children, filter_children_preserving, get_tag_name, is_l_val,
+            is_static_expr, lit_to_string, make_getter_prop, make_jsx_attr_expr,
+            make_member_assign, make_var_declarator, number_to_string, to_property_name,
+            trim_whitespace, unwrap_arrow_body, unwrap_ts_expr, with_span,
         },
     },
 };
 use swc_core::{
     atoms::wtf8::CodePoint,
-    common::{DUMMY_SP, comments::Comments},
+    common::{DUMMY_SP, Span, comments::Comments},
     ecma::{
         ast::*,
-        minifier::eval::EvalResult,
         utils::{ExprFactory, quote_ident},
     },
     quote,
@@ -48,12 +50,18 @@ where
             info.top_level && tag_name != "svg" && SVG_ELEMENTS.contains(&tag_name.as_str());
         let void_tag = VOID_ELEMENTS.contains(&tag_name.as_str());
         let is_custom_element = tag_name.contains('-');
+        // the content of raw text elements is read verbatim by the parser
+        let raw_text = matches!(tag_name.as_str(), "script" | "style");
         let mut results = TemplateInstantiation {
             template: format!("<{tag_name}"),
             tag_name: tag_name.clone(),
             is_svg: wrap_svg,
             is_void: void_tag,
             has_custom_element: is_custom_element,
+            preserve_whitespace: raw_text
+                || info.preserve_whitespace
+                || self.preserves_whitespace(&tag_name),
+            do_not_escape: raw_text,
             ..Default::default()
         };
         if wrap_svg {
@@ -62,6 +70,18 @@ where
         if !info.skip_id {
             results.id = Some(self.generate_uid_identifier("el$"));
         }
+        if self.config.hydratable && matches!(tag_name.as_str(), "html" | "head" | "body") {
+            results.skip_template = true;
+            if tag_name == "head" && info.top_level {
+                let no_hydration = self.create_no_hydration();
+                results.exprs.push(no_hydration);
+                return results;
+            }
+        }
+        self.validate_children(&tag_name, &node.children);
+        if raw_text {
+            self.raw_text_to_text_content(&mut node);
+        }
         let child =
             self.transform_attributes(node.opening.attrs, !node.children.is_empty(), &mut results);
         if let Some(child) = child
@@ -72,6 +92,14 @@ where
         if self.config.context_to_custom_elements && (tag_name == "slot" || is_custom_element) {
             self.context_to_custom_element(&mut results);
         }
+        if self.config.dev
+            && let Some(location) = self.source_location(node.span)
+        {
+            results.template += &format!(
+                r#" data-source-loc="{}""#,
+                escape_backticks(&escape_html(&location, true))
+            );
+        }
         results.template += ">";
 
         if !void_tag {
@@ -93,7 +121,13 @@ where
             } else {
                 results.to_be_closed = info.to_be_closed.cloned();
             }
+            let content_start = results.template.len();
             self.transform_children(node.children, &mut results);
+            if drops_leading_newline(&tag_name)
+                && results.template[content_start..].starts_with('\n')
+            {
+                results.template.insert(content_start, '\n');
+            }
             if to_be_closed {
                 results.template += &format!("</{tag_name}>");
             }
@@ -101,9 +135,85 @@ where
         if wrap_svg {
             results.template += "</svg>";
         }
+        if info.top_level && self.config.hydratable && results.has_hydratable_event {
+            results.post_exprs.push(quote!(
+                "$run_hydration_events()" as Expr,
+                run_hydration_events = self.register_import_method("runHydrationEvents")
+            ));
+        }
         results
     }
 
+    /// Moves the children of a `<script>` or `<style>` into a `textContent`
+    /// attribute when any of them is dynamic, as `insert` would add text
+    /// nodes the element never renders. Static content stays in the template.
+    fn raw_text_to_text_content(&mut self, node: &mut JSXElement) {
+        let children: Vec<_> = node
+            .children
+            .iter()
+            .filter(|child| filter_children(child))
+            .collect();
+        if children.iter().all(|child| match child {
+            JSXElementChild::JSXExprContainer(_) => self.get_static_expression(child).is_some(),
+            _ => true,
+        }) {
+            return;
+        }
+        let value = match children.as_slice() {
+            [
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }),
+            ] => *expr.clone(),
+            _ => {
+                let mut quasis = vec![];
+                let mut exprs = vec![];
+                let mut text = String::new();
+                for child in std::mem::take(&mut node.children) {
+                    if let Some(value) = self.get_static_expression(&child) {
+                        text += &value;
+                        continue;
+                    }
+                    match child {
+                        JSXElementChild::JSXText(t) => text += &t.value,
+                        JSXElementChild::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        }) => {
+                            quasis.push(make_tpl_element(&text, false));
+                            exprs.push(expr);
+                            text.clear();
+                        }
+                        _ => {}
+                    }
+                }
+                quasis.push(make_tpl_element(&text, true));
+                Tpl {
+                    span: DUMMY_SP,
+                    exprs,
+                    quasis,
+                }
+                .into()
+            }
+        };
+        node.children.clear();
+        node.opening.attrs.push(make_jsx_attr_expr(
+            quote_ident!("textContent").into(),
+            value,
+            DUMMY_SP,
+        ));
+    }
+
+    /// `<head>` is owned by the server render, so hydration skips over it.
+    fn create_no_hydration(&mut self) -> Expr {
+        quote!(
+            "$create_component($no_hydration, {})" as Expr,
+            create_component = self.register_import_method("createComponent"),
+            no_hydration = self.register_import_method("NoHydration")
+        )
+    }
+
     pub fn set_attr(
         &mut self,
         elem: Ident,
@@ -132,10 +242,8 @@ where
                     }
                     _ => {}
                 },
-                Expr::Ident(id) => {
-                    if id.sym == "undefined" {
-                        return quote!("$elem.style.removeProperty($name)" as Expr, elem = elem, name: Expr = *name);
-                    }
+                Expr::Ident(id) if id.sym == "undefined" => {
+                    return quote!("$elem.style.removeProperty($name)" as Expr, elem = elem, name: Expr = *name);
                 }
                 _ => {}
             }
@@ -288,18 +396,87 @@ where
                     .as_ref()
                     .is_some_and(|init| self.detect_resolvable_event_handler(init));
             }
-            return self.binding_collector.function_bindings.contains(&id.to_id());
+            return self
+                .binding_collector
+                .function_bindings
+                .contains(&id.to_id());
         }
         matches!(handler, Expr::Fn(_) | Expr::Arrow(_))
     }
 
+    /// Binds `ref={...}` to the element created for `results`.
+    pub fn transform_element_ref(&mut self, expr: Expr, results: &mut TemplateInstantiation) {
+        let expr = unwrap_ts_expr(expr);
+        let is_function = expr.as_ident().is_some_and(|id| {
+            self.binding_collector
+                .const_var_bindings
+                .contains_key(&id.to_id())
+        });
+
+        let el_ident = results.id.clone().unwrap();
+        if !is_function && is_l_val(&expr) {
+            let ref_ident = self.generate_uid_identifier("_ref$");
+            results
+                .declarations
+                .insert(0, make_var_declarator(ref_ident.clone(), expr.clone()));
+
+            let use_hook = self.register_import_method("use");
+            let assign = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: AssignOp::Assign,
+                left: AssignTarget::Simple(SimpleAssignTarget::Paren(ParenExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(expr),
+                })),
+                right: Box::new(Expr::Ident(el_ident.clone())),
+            });
+            results.exprs.insert(
+                0,
+                quote!(
+                    "typeof $ref_ident === \"function\" ? $use_hook($ref_ident, $el_ident) : $assign"
+                        as Expr,
+                    ref_ident = ref_ident,
+                    use_hook = use_hook,
+                    el_ident = el_ident,
+                    assign: Expr = assign
+                ),
+            );
+        } else if is_function || matches!(expr, Expr::Fn(_) | Expr::Arrow(_)) {
+            results.exprs.insert(
+                0,
+                quote!(
+                    "$use_hook($target, $el_ident)" as Expr,
+                    use_hook = self.register_import_method("use"),
+                    target: Expr = expr,
+                    el_ident = el_ident
+                ),
+            );
+        } else if matches!(expr, Expr::Call(_)) {
+            let ref_ident = self.generate_uid_identifier("_ref$");
+            results
+                .declarations
+                .insert(0, make_var_declarator(ref_ident.clone(), expr));
+
+            results.exprs.insert(
+                0,
+                quote!(
+                    "typeof $ref_ident === \"function\" && $use_hook($ref_ident, $el_ident)"
+                        as Expr,
+                    ref_ident = ref_ident,
+                    use_hook = self.register_import_method("use"),
+                    el_ident = el_ident
+                ),
+            );
+        }
+    }
+
     fn transform_attributes(
         &mut self,
         mut attributes: Vec<JSXAttrOrSpread>,
         has_children: bool,
         results: &mut TemplateInstantiation,
     ) -> Option<JSXElementChild> {
-        let elem = &results.id;
+        let elem = results.id.clone();
         let mut children = None;
         let mut spread_expr = Expr::Invalid(Invalid { span: DUMMY_SP });
         let is_svg = SVG_ELEMENTS.contains(&results.tag_name.as_str());
@@ -323,6 +500,24 @@ where
             );
         }
 
+        // inline `style={STYLE}` and `classList={CLASSES}` from constant objects
+        for attribute in attributes.iter_mut() {
+            if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                value:
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    })),
+                ..
+            }) = attribute
+                && (&name.sym == "style" || &name.sym == "classList")
+                && let Some(object) = self.eval_object(expr)
+            {
+                **expr = object.into();
+            }
+        }
+
         // preprocess styles
         let style_props = attributes.iter().enumerate().find_map(|(i, a)| {
             match a {
@@ -361,13 +556,13 @@ where
                         Prop::KeyValue(ref kv) => match kv.key {
                             PropName::Ident(ref id) => handle(id.clone(), *kv.value.clone()),
                             PropName::Str(ref s) => {
-                                let a = s.value.as_atom().unwrap().to_owned();
+                                let a = s.value.to_string_lossy().to_string();
                                 handle(quote_ident!(a), *kv.value.clone())
                             }
-                            PropName::Computed(_) => true,
-                            _ => panic!(),
+                            // left in the style object for the runtime to apply
+                            _ => true,
                         },
-                        _ => panic!("Expect ident or key value prop for style attr"),
+                        _ => true,
                     };
                 }
                 true
@@ -425,14 +620,17 @@ where
             let mut i = 0usize;
             props.retain(|prop| {
                 let mut handle = |name: IdentName, value: Expr| {
-                    i += 1;
                     let attr = match self.eval(&value) {
-                        Some(EvalResult::Lit(_)) => JSXAttrOrSpread::JSXAttr(JSXAttr {
-                            span: DUMMY_SP,
-                            name: JSXAttrName::Ident(quote_ident!("class")),
-                            value: Some(JSXAttrValue::Str(name.sym.to_string().into())),
-                        }),
-                        _ => {
+                        Some(EvalResult::Lit(lit)) if is_truthy(&lit) => {
+                            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                                span: DUMMY_SP,
+                                name: JSXAttrName::Ident(quote_ident!("class")),
+                                value: Some(JSXAttrValue::Str(name.sym.to_string().into())),
+                            })
+                        }
+                        // classes that are always off are dropped
+                        Some(_) => return false,
+                        None => {
                             let attr_name = JSXAttrName::JSXNamespacedName(JSXNamespacedName {
                                 span,
                                 ns: quote_ident!("class"),
@@ -441,6 +639,7 @@ where
                             make_jsx_attr_expr(attr_name, value, span)
                         }
                     };
+                    i += 1;
                     attributes.insert(class_list_idx + i, attr);
                     false
                 };
@@ -453,7 +652,7 @@ where
                         Prop::KeyValue(ref kv) => match kv.key {
                             PropName::Ident(ref id) => handle(id.clone(), *kv.value.clone()),
                             PropName::Str(ref s) => {
-                                let a = s.value.as_atom().unwrap().to_owned();
+                                let a = s.value.to_string_lossy().to_string();
                                 handle(quote_ident!(a), *kv.value.clone())
                             }
                             _ => true,
@@ -583,6 +782,7 @@ where
                 JSXAttrOrSpread::JSXAttr(attr) => attr,
                 JSXAttrOrSpread::SpreadElement(_) => panic!("Spread wasn't preprocessed"),
             };
+            let attr_span = attribute.span;
 
             let mut reserved_name_space = false;
             let key = match &attribute.name {
@@ -600,12 +800,30 @@ where
                     ..
                 })) = attribute.value
             {
+                let literal = expr.is_lit();
                 match self.eval(expr) {
                     Some(EvalResult::Lit(Lit::Str(lit))) => {
                         attribute.value = Some(JSXAttrValue::Str(lit))
                     }
                     Some(EvalResult::Lit(Lit::Num(lit))) => {
-                        attribute.value = Some(JSXAttrValue::Str(lit.value.to_string().into()))
+                        attribute.value =
+                            Some(JSXAttrValue::Str(number_to_string(lit.value).into()))
+                    }
+                    // folded boolean constants are written into the template,
+                    // or left out when off
+                    Some(EvalResult::Lit(Lit::Bool(value)))
+                        if !literal && !reserved_name_space && BOOLEANS.contains(&key.as_str()) =>
+                    {
+                        if !value.value {
+                            continue;
+                        }
+                        attribute.value = None;
+                    }
+                    Some(EvalResult::Lit(lit @ Lit::Bool(_))) => {
+                        attribute.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            span: DUMMY_SP,
+                            expr: JSXExpr::Expr(Box::new(lit.into())),
+                        }))
                     }
                     _ => {}
                 };
@@ -618,6 +836,23 @@ where
                 }))
             }
 
+            // JSX passed as an attribute value is set like any other expression
+            attribute.value = match attribute.value.take() {
+                Some(JSXAttrValue::JSXElement(el)) => {
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        span: el.span,
+                        expr: JSXExpr::Expr(Box::new(Expr::JSXElement(el))),
+                    }))
+                }
+                Some(JSXAttrValue::JSXFragment(frag)) => {
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        span: frag.span,
+                        expr: JSXExpr::Expr(Box::new(Expr::JSXFragment(frag))),
+                    }))
+                }
+                value => value,
+            };
+
             if let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
                 expr: JSXExpr::Expr(expr),
                 span,
@@ -627,65 +862,7 @@ where
                     || !matches!(expr.as_lit(), Some(Lit::Str(_)) | Some(Lit::Num(_)))
                 {
                     if key == "ref" {
-                        let expr = unwrap_ts_expr(*expr);
-                        let is_function = expr
-                            .as_ident()
-                            .is_some_and(|id| self.binding_collector.const_var_bindings.contains_key(&id.to_id()));
-
-                        let el_ident = results.id.clone().unwrap();
-                        if !is_function && is_l_val(&expr) {
-                            let ref_ident = self.generate_uid_identifier("_ref$");
-                            results
-                                .declarations
-                                .insert(0, make_var_declarator(ref_ident.clone(), expr.clone()));
-
-                            let use_hook = self.register_import_method("use");
-                            let assign = Expr::Assign(AssignExpr {
-                                span: DUMMY_SP,
-                                op: AssignOp::Assign,
-                                left: AssignTarget::Simple(SimpleAssignTarget::Paren(ParenExpr {
-                                    span: DUMMY_SP,
-                                    expr: Box::new(expr),
-                                })),
-                                right: Box::new(Expr::Ident(el_ident.clone())),
-                            });
-                            results.exprs.insert(
-                                0,
-                                quote!(
-                                    "typeof $ref_ident === \"function\" ? $use_hook($ref_ident, $el_ident) : $assign"
-                                        as Expr,
-                                    ref_ident = ref_ident,
-                                    use_hook = use_hook,
-                                    el_ident = el_ident,
-                                    assign: Expr = assign
-                                ),
-                            );
-                        } else if is_function || matches!(expr, Expr::Fn(_) | Expr::Arrow(_)) {
-                            results.exprs.insert(
-                                0,
-                                quote!(
-                                    "$use_hook($target, $el_ident)" as Expr,
-                                    use_hook = self.register_import_method("use"),
-                                    target: Expr = expr,
-                                    el_ident = el_ident
-                                ),
-                            );
-                        } else if matches!(expr, Expr::Call(_)) {
-                            let ref_ident = self.generate_uid_identifier("_ref$");
-                            results
-                                .declarations
-                                .insert(0, make_var_declarator(ref_ident.clone(), expr));
-
-                            results.exprs.insert(
-                                0,
-                                quote!(
-                                    "typeof $ref_ident === \"function\" && $use_hook($ref_ident, $el_ident)" as Expr,
-                                    ref_ident = ref_ident,
-                                    use_hook = self.register_import_method("use"),
-                                    el_ident = el_ident
-                                ),
-                            );
-                        }
+                        self.transform_element_ref(*expr, results);
                     } else if key.starts_with("use:") {
                         if let JSXAttrName::JSXNamespacedName(name) = &attribute.name {
                             let use_hook = self.register_import_method("use");
@@ -693,12 +870,15 @@ where
                             let el_id = results.id.clone().unwrap();
                             results.exprs.insert(
                                 0,
-                                quote!(
-                                    "$use_hook($name, $el_id, () => $arrow)" as Expr,
-                                    use_hook = use_hook,
-                                    name: Ident = name_id.into(),
-                                    el_id = el_id,
-                                    arrow: Expr = *expr
+                                with_span(
+                                    quote!(
+                                        "$use_hook($name, $el_id, () => $arrow)" as Expr,
+                                        use_hook = use_hook,
+                                        name: Ident = name_id.into(),
+                                        el_id = el_id,
+                                        arrow: Expr = *expr
+                                    ),
+                                    attr_span,
                                 ),
                             );
                         }
@@ -713,111 +893,141 @@ where
                         if key.starts_with("on:") || key.starts_with("oncapture:") {
                             let event_name = key.split(':').nth(1).unwrap();
                             if key.starts_with("oncapture:") {
-                                results.exprs.push(quote!(
-                                    "$el.addEventListener($event_name, $expr, true)" as Expr,
-                                    el = el_ident,
-                                    event_name: Expr = event_name.into(),
-                                    expr: Expr = *expr
+                                results.exprs.push(with_span(
+                                    quote!(
+                                        "$el.addEventListener($event_name, $expr, true)" as Expr,
+                                        el = el_ident,
+                                        event_name: Expr = event_name.into(),
+                                        expr: Expr = *expr
+                                    ),
+                                    attr_span,
                                 ));
                             } else {
-                                results.exprs.push(quote!(
-                                    "$el.addEventListener($event_name, $expr)" as Expr,
-                                    el = el_ident,
-                                    event_name: Expr = event_name.into(),
-                                    expr: Expr = *expr
+                                results.exprs.push(with_span(
+                                    quote!(
+                                        "$el.addEventListener($event_name, $expr)" as Expr,
+                                        el = el_ident,
+                                        event_name: Expr = event_name.into(),
+                                        expr: Expr = *expr
+                                    ),
+                                    attr_span,
                                 ));
                             }
                         } else if self.config.delegate_events
                             && (DELEGATED_EVENTS.contains(&ev.as_ref())
                                 || self.config.delegated_events.contains(&ev.to_string()))
+                            && !self.config.non_delegated_events.contains(&ev.to_string())
                         {
                             self.events.insert(ev.clone());
+                            // only delegated events can be replayed on hydration
+                            results.has_hydratable_event = true;
                             let el_ident = results.id.clone().unwrap();
                             let resolveable = self.detect_resolvable_event_handler(&expr);
-                            if let Expr::Array(ref arr_lit) = *expr {
-                                if arr_lit.elems.len() > 1 {
+                            if let Some((handler, data)) = split_bound_handler(&expr, attr_span) {
+                                if let Some(data) = data {
                                     results.exprs.insert(
                                         0,
-                                        make_member_assign(
-                                            el_ident.clone(),
-                                            &format!("$${}Data", ev),
-                                            *arr_lit.elems[1].clone().unwrap().expr,
+                                        with_span(
+                                            make_member_assign(
+                                                el_ident.clone(),
+                                                &format!("$${}Data", ev),
+                                                data,
+                                            ),
+                                            attr_span,
                                         ),
                                     );
                                 }
                                 results.exprs.insert(
                                     0,
-                                    make_member_assign(
-                                        el_ident.clone(),
-                                        &format!("$${}", ev),
-                                        *arr_lit.elems[0].clone().unwrap().expr,
+                                    with_span(
+                                        make_member_assign(
+                                            el_ident.clone(),
+                                            &format!("$${}", ev),
+                                            handler,
+                                        ),
+                                        attr_span,
                                     ),
                                 )
                             } else if matches!(*expr, Expr::Fn(_) | Expr::Arrow(_)) || resolveable {
                                 results.exprs.insert(
                                     0,
-                                    make_member_assign(el_ident, &format!("$${}", ev), *expr),
+                                    with_span(
+                                        make_member_assign(el_ident, &format!("$${}", ev), *expr),
+                                        attr_span,
+                                    ),
                                 )
                             } else {
                                 results.exprs.insert(
                                     0,
-                                    quote!(
-                                        "$add_event_listener($el, $ev, $expr, true)" as Expr,
-                                        add_event_listener =
-                                            self.register_import_method("addEventListener"),
-                                        el = el_ident,
-                                        ev: Expr = ev.into(),
-                                        expr: Expr = *expr
+                                    with_span(
+                                        quote!(
+                                            "$add_event_listener($el, $ev, $expr, true)" as Expr,
+                                            add_event_listener =
+                                                self.register_import_method("addEventListener"),
+                                            el = el_ident,
+                                            ev: Expr = ev.into(),
+                                            expr: Expr = *expr
+                                        ),
+                                        attr_span,
                                     ),
                                 )
                             }
                         } else {
                             let resolveable = self.detect_resolvable_event_handler(&expr);
-                            if let Expr::Array(ref arr_lit) = *expr {
-                                let handler = if arr_lit.elems.len() > 1 {
+                            if let Some((handler, data)) = split_bound_handler(&expr, attr_span) {
+                                let handler = if let Some(data) = data {
                                     Expr::Arrow(ArrowExpr {
                                         span: DUMMY_SP,
                                         params: vec![Pat::Ident(quote_ident!("e").into())],
                                         body: Box::new(BlockStmtOrExpr::Expr(Box::new(quote!(
                                             "$myfn($data, $e)" as Expr,
-                                            myfn: Expr = *arr_lit.elems[0].clone().unwrap().expr,
-                                            data: Expr = *arr_lit.elems[1].clone().unwrap().expr,
+                                            myfn: Expr = handler,
+                                            data: Expr = data,
                                             e = quote_ident!("e").into()
                                         )))),
                                         ..Default::default()
                                     })
                                 } else {
-                                    *arr_lit.elems[0].clone().unwrap().expr
+                                    handler
                                 };
                                 results.exprs.insert(
                                     0,
-                                    quote!(
-                                        "$el.addEventListener($ev, $handler)" as Expr,
-                                        el = el_ident,
-                                        ev: Expr = ev.into(),
-                                        handler: Expr = handler
+                                    with_span(
+                                        quote!(
+                                            "$el.addEventListener($ev, $handler)" as Expr,
+                                            el = el_ident,
+                                            ev: Expr = ev.into(),
+                                            handler: Expr = handler
+                                        ),
+                                        attr_span,
                                     ),
                                 );
                             } else if matches!(*expr, Expr::Fn(_) | Expr::Arrow(_)) || resolveable {
                                 results.exprs.insert(
                                     0,
-                                    quote!(
-                                        "$el.addEventListener($ev, $expr)" as Expr,
-                                        el = el_ident,
-                                        ev: Expr = ev.into(),
-                                        expr: Expr = *expr
+                                    with_span(
+                                        quote!(
+                                            "$el.addEventListener($ev, $expr)" as Expr,
+                                            el = el_ident,
+                                            ev: Expr = ev.into(),
+                                            expr: Expr = *expr
+                                        ),
+                                        attr_span,
                                     ),
                                 );
                             } else {
                                 results.exprs.insert(
                                     0,
-                                    quote!(
-                                        "$add_event_listener($el, $ev, $expr)" as Expr,
-                                        add_event_listener =
-                                            self.register_import_method("addEventListener"),
-                                        el = el_ident,
-                                        ev: Expr = ev.into(),
-                                        expr: Expr = *expr
+                                    with_span(
+                                        quote!(
+                                            "$add_event_listener($el, $ev, $expr)" as Expr,
+                                            add_event_listener =
+                                                self.register_import_method("addEventListener"),
+                                            el = el_ident,
+                                            ev: Expr = ev.into(),
+                                            expr: Expr = *expr
+                                        ),
+                                        attr_span,
                                     ),
                                 );
                             }
@@ -844,10 +1054,13 @@ where
                                     tag_name: results.tag_name.clone(),
                                 },
                             );
-                            results.post_exprs.push(quote!(
-                                "$effect_wrapper(() => $setter)" as Expr,
-                                effect_wrapper = effect_wrapper,
-                                setter: Expr = setter
+                            results.post_exprs.push(with_span(
+                                quote!(
+                                    "$effect_wrapper(() => $setter)" as Expr,
+                                    effect_wrapper = effect_wrapper,
+                                    setter: Expr = setter
+                                ),
+                                attr_span,
                             ));
                             continue;
                         }
@@ -874,19 +1087,23 @@ where
                             is_svg,
                             is_ce,
                             tag_name: results.tag_name.clone(),
+                            span: attr_span,
                         });
                     } else {
-                        results.exprs.push(self.set_attr(
-                            elem.clone().unwrap(),
-                            &key,
-                            *expr,
-                            &AttrOptions {
-                                is_svg,
-                                dynamic: false,
-                                prev_id: None,
-                                is_ce,
-                                tag_name: results.tag_name.clone(),
-                            },
+                        results.exprs.push(with_span(
+                            self.set_attr(
+                                elem.clone().unwrap(),
+                                &key,
+                                *expr,
+                                &AttrOptions {
+                                    is_svg,
+                                    dynamic: false,
+                                    prev_id: None,
+                                    is_ce,
+                                    tag_name: results.tag_name.clone(),
+                                },
+                            ),
+                            attr_span,
                         ))
                     }
                 }
@@ -895,19 +1112,24 @@ where
                     Some(ref mut value) => {
                         let expr = match value {
                             JSXAttrValue::JSXExprContainer(value) => match &value.expr {
-                                JSXExpr::JSXEmptyExpr(_) => {
-                                    panic!("Empty expression not allowed")
+                                JSXExpr::Expr(expr) if expr.is_lit() => {
+                                    expr.as_lit().unwrap().clone()
+                                }
+                                _ => {
+                                    emit_error(
+                                        value.span,
+                                        "JSX attributes must only be assigned a non-empty expression",
+                                    );
+                                    continue;
                                 }
-                                JSXExpr::Expr(expr) => match expr.as_ref() {
-                                    Expr::Lit(value) => value.clone(),
-                                    _ => panic!(),
-                                },
                             },
                             JSXAttrValue::Str(value) => {
                                 // todo fix double newlines in test dom attribute-expressions template30
                                 Lit::Str(value.clone())
                             }
-                            _ => panic!(),
+                            JSXAttrValue::JSXElement(_) | JSXAttrValue::JSXFragment(_) => {
+                                continue;
+                            }
                         };
                         Some(expr)
                     }
@@ -921,17 +1143,20 @@ where
 
                 match value {
                     Some(value) if CHILD_PROPERTIES.contains(key.as_str()) => {
-                        results.exprs.push(self.set_attr(
-                            elem.clone().unwrap(),
-                            &key,
-                            Expr::Lit(value),
-                            &AttrOptions {
-                                is_svg,
-                                dynamic: false,
-                                is_ce,
-                                prev_id: None,
-                                tag_name: results.tag_name.clone(),
-                            },
+                        results.exprs.push(with_span(
+                            self.set_attr(
+                                elem.clone().unwrap(),
+                                &key,
+                                Expr::Lit(value),
+                                &AttrOptions {
+                                    is_svg,
+                                    dynamic: false,
+                                    is_ce,
+                                    prev_id: None,
+                                    tag_name: results.tag_name.clone(),
+                                },
+                            ),
+                            attr_span,
                         ))
                     }
                     _ => {
@@ -1016,6 +1241,36 @@ where
         &mut self,
         attributes: Vec<JSXAttrOrSpread>,
         info: ProcessSpreadsInfo,
+    ) -> (Vec<JSXAttrOrSpread>, Expr) {
+        let (filtered_attributes, props) =
+            self.process_spread_props(attributes, info.wrap_conditionals);
+        let spread = self.register_import_method("spread");
+        let elem_arg: Expr = info
+            .elem
+            .map(Expr::Ident)
+            .unwrap_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })));
+        (
+            filtered_attributes,
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(spread))),
+                args: vec![
+                    elem_arg.into(),
+                    props.into(),
+                    Expr::Lit(Lit::Bool(info.is_svg.into())).into(),
+                    Expr::Lit(Lit::Bool(info.has_children.into())).into(),
+                ],
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Splits spread-affected attributes out of `attributes` and merges them
+    /// into a single props expression.
+    pub fn process_spread_props(
+        &mut self,
+        attributes: Vec<JSXAttrOrSpread>,
+        wrap_conditionals: bool,
     ) -> (Vec<JSXAttrOrSpread>, Expr) {
         let mut filtered_attributes: Vec<JSXAttrOrSpread> = vec![];
         let mut spread_args: Vec<Expr> = vec![];
@@ -1081,19 +1336,11 @@ where
                             ..
                         })) = attr.value
                         {
-                            if info.wrap_conditionals
+                            if wrap_conditionals
                                 && (matches!(**ex, Expr::Bin(_)) || matches!(**ex, Expr::Cond(_)))
                             {
                                 let (_, b) = self.transform_condition(*ex.clone(), true, false);
-                                if let Expr::Arrow(arr) = b {
-                                    if let BlockStmtOrExpr::Expr(e) = *arr.body {
-                                        expr = e;
-                                    } else {
-                                        panic!("Can't handle this");
-                                    }
-                                } else {
-                                    panic!("Can't handle this");
-                                }
+                                expr = Box::new(unwrap_arrow_body(b));
                             } else {
                                 expr = ex.clone();
                             }
@@ -1112,9 +1359,18 @@ where
                             } else if let Some(ref v) = attr.value {
                                 match v {
                                     JSXAttrValue::Str(l) => Expr::Lit(Lit::Str(l.clone())),
-                                    _ => panic!("Can't handle this"),
+                                    JSXAttrValue::JSXElement(el) => Expr::JSXElement(el.clone()),
+                                    JSXAttrValue::JSXFragment(frag) => {
+                                        Expr::JSXFragment(frag.clone())
+                                    }
+                                    JSXAttrValue::JSXExprContainer(_) => {
+                                        Expr::Lit(Lit::Bool(true.into()))
+                                    }
                                 }
-                            } else if PROPERTIES.contains(key.as_str()) {
+                            } else if PROPERTIES.contains(key.as_str())
+                                || self.config.generate == Generate::Universal
+                                || self.renderer.as_ref().is_some_and(|r| !r.dom)
+                            {
                                 Expr::Lit(Lit::Bool(true.into()))
                             } else {
                                 Expr::Lit(Lit::Str(Str {
@@ -1161,25 +1417,7 @@ where
             })
         };
 
-        let spread = self.register_import_method("spread");
-        let elem_arg: Expr = info
-            .elem
-            .map(Expr::Ident)
-            .unwrap_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })));
-        (
-            filtered_attributes,
-            Expr::Call(CallExpr {
-                span: DUMMY_SP,
-                callee: Callee::Expr(Box::new(Expr::Ident(spread))),
-                args: vec![
-                    elem_arg.into(),
-                    props.into(),
-                    Expr::Lit(Lit::Bool(info.is_svg.into())).into(),
-                    Expr::Lit(Lit::Bool(info.has_children.into())).into(),
-                ],
-                ..Default::default()
-            }),
-        )
+        (filtered_attributes, props)
     }
 }
 
@@ -1197,9 +1435,13 @@ where
         let mut i = 0;
         let filtered_children = children
             .into_iter()
-            .filter(filter_children)
+            .filter(filter_children_preserving(results.preserve_whitespace))
             .collect::<Vec<JSXElementChild>>();
-        let last_element = self.find_last_element(&filtered_children);
+        let last_element = if self.config.hydratable {
+            filtered_children.len() as i32 - 1
+        } else {
+            self.find_last_element(&filtered_children)
+        };
 
         let children_refs: Vec<&JSXElementChild> = filtered_children.iter().collect();
         let detect_exprs: Vec<bool> = children_refs
@@ -1207,7 +1449,7 @@ where
             .enumerate()
             .map(|(index, _)| self.detect_expressions(&children_refs, index))
             .collect();
-        let multi = check_length(&filtered_children);
+        let multi = check_length(&filtered_children, results.preserve_whitespace);
         let child_nodes = filtered_children
             .into_iter()
             .enumerate()
@@ -1215,11 +1457,15 @@ where
             .fold(
                 Vec::<TemplateInstantiation>::new(),
                 |mut memo, ((index, child), detect_expressions)| {
-                    if let JSXElementChild::JSXFragment(_) = child {
-                        panic!(
-                            "Fragments can only be used top level in JSX. Not used under a <{}>.",
-                            results.tag_name
+                    if let JSXElementChild::JSXFragment(ref fragment) = child {
+                        emit_error(
+                            fragment.span,
+                            &format!(
+                                "Fragments can only be used top level in JSX. Not used under a <{}>.",
+                                results.tag_name
+                            ),
                         );
+                        return memo;
                     }
                     let transformed = self.transform_node(
                         child,
@@ -1227,6 +1473,8 @@ where
                             to_be_closed: results.to_be_closed.as_ref(),
                             last_element: index == last_element as usize,
                             skip_id: results.id.is_none() || !detect_expressions,
+                            preserve_whitespace: results.preserve_whitespace,
+                            do_not_escape: results.do_not_escape,
                             ..Default::default()
                         },
                     );
@@ -1297,16 +1545,28 @@ where
             results.template += &child.template;
             if child.id.is_some() {
                 if child.tag_name == "head" {
+                    if self.config.hydratable {
+                        let no_hydration = self.create_no_hydration();
+                        results.exprs.push(no_hydration);
+                    }
                     continue;
                 }
 
                 let temp_path_id = temp_path.clone().unwrap();
 
-                let init = if i == 0 {
+                let mut init = if i == 0 {
                     quote!("$temp_path.firstChild" as Expr, temp_path = temp_path_id)
                 } else {
                     quote!("$temp_path.nextSibling" as Expr, temp_path = temp_path_id)
                 };
+                if self.config.hydratable && results.tag_name == "html" {
+                    init = quote!(
+                        "$get_next_match($walk, $tag_name)" as Expr,
+                        get_next_match = self.register_import_method("getNextMatch"),
+                        walk: Expr = init,
+                        tag_name: Expr = child.tag_name.clone().into()
+                    );
+                }
 
                 results
                     .declarations
@@ -1316,60 +1576,85 @@ where
                 results.dynamics.extend(child.dynamics);
                 results.post_exprs.extend(child.post_exprs);
                 results.has_custom_element |= child.has_custom_element;
+                results.has_hydratable_event |= child.has_hydratable_event;
                 temp_path.clone_from(&child.id);
                 next_placeholder = None;
                 i += 1;
             } else if !child.exprs.is_empty() {
                 let insert = self.register_import_method("insert");
+                let child_span = child.span;
                 let child_expr = child.exprs.into_first();
 
-                if wrapped_info[index] {
+                // hydration needs every inserted expression boxed by comment markers
+                let markers = self.config.hydratable && multi;
+                if markers || wrapped_info[index] {
                     let expr_id;
                     let mut content_id = None;
+                    if markers {
+                        let (start_id, _) = self.create_placeholder(results, &temp_path, i, "$");
+                        temp_path = Some(start_id);
+                        i += 1;
+                    }
                     if let Some(placeholder) = next_placeholder.clone() {
                         expr_id = placeholder;
                     } else {
-                        (expr_id, content_id) = self.create_placeholder(results, &temp_path, i, "");
+                        (expr_id, content_id) = self.create_placeholder(
+                            results,
+                            &temp_path,
+                            i,
+                            if markers { "/" } else { "" },
+                        );
                         i += 1;
                     }
-                    next_placeholder = Some(expr_id.clone());
-                    results.exprs.push(if let Some(content_id) = content_id {
-                        quote!(
-                            "$insert($id, $child, $expr_id, $content_id)" as Expr,
-                            insert = insert,
-                            id = results.id.clone().unwrap(),
-                            child: Expr = child_expr,
-                            expr_id = expr_id.clone(),
-                            content_id: Expr = *content_id.expr
-                        )
-                    } else {
-                        quote!(
-                            "$insert($id, $child, $expr_id)" as Expr,
-                            insert = insert,
-                            id = results.id.clone().unwrap(),
-                            child: Expr = child_expr,
-                            expr_id = expr_id.clone()
-                        )
-                    });
+                    if !markers {
+                        next_placeholder = Some(expr_id.clone());
+                    }
+                    results.exprs.push(with_span(
+                        if let Some(content_id) = content_id {
+                            quote!(
+                                "$insert($id, $child, $expr_id, $content_id)" as Expr,
+                                insert = insert,
+                                id = results.id.clone().unwrap(),
+                                child: Expr = child_expr,
+                                expr_id = expr_id.clone(),
+                                content_id: Expr = *content_id.expr
+                            )
+                        } else {
+                            quote!(
+                                "$insert($id, $child, $expr_id)" as Expr,
+                                insert = insert,
+                                id = results.id.clone().unwrap(),
+                                child: Expr = child_expr,
+                                expr_id = expr_id.clone()
+                            )
+                        },
+                        child_span,
+                    ));
                     temp_path = Some(expr_id);
                 } else if multi {
                     let next_child_id = next_children[index]
                         .clone()
                         .map(|x| x.into())
                         .unwrap_or(quote!("null" as Expr));
-                    results.exprs.push(quote!(
-                        "$insert($result_id, $child_expr, $next_child)" as Expr,
-                        insert = insert,
-                        result_id = results.id.clone().unwrap(),
-                        child_expr: Expr = child_expr,
-                        next_child: Expr = next_child_id
+                    results.exprs.push(with_span(
+                        quote!(
+                            "$insert($result_id, $child_expr, $next_child)" as Expr,
+                            insert = insert,
+                            result_id = results.id.clone().unwrap(),
+                            child_expr: Expr = child_expr,
+                            next_child: Expr = next_child_id
+                        ),
+                        child_span,
                     ));
                 } else {
-                    results.exprs.push(quote!(
-                        "$insert($result_id, $child_expr)" as Expr,
-                        insert = insert,
-                        result_id = results.id.clone().unwrap(),
-                        child_expr: Expr = child_expr
+                    results.exprs.push(with_span(
+                        quote!(
+                            "$insert($result_id, $child_expr)" as Expr,
+                            insert = insert,
+                            result_id = results.id.clone().unwrap(),
+                            child_expr: Expr = child_expr
+                        ),
+                        child_span,
                     ));
                 }
             } else {
@@ -1388,6 +1673,28 @@ where
         let expr_id = self.generate_uid_identifier("el$");
         results.template += &format!("<!{char}>");
         let temp_path_id = temp_path.clone().unwrap();
+        if self.config.hydratable && char == "/" {
+            let content_id = self.generate_uid_identifier("co$");
+            results.declarations.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Array(ArrayPat {
+                    span: DUMMY_SP,
+                    elems: vec![
+                        Some(Pat::Ident(expr_id.clone().into())),
+                        Some(Pat::Ident(content_id.clone().into())),
+                    ],
+                    optional: false,
+                    type_ann: None,
+                }),
+                init: Some(Box::new(quote!(
+                    "$get_next_marker($temp_path.nextSibling)" as Expr,
+                    get_next_marker = self.register_import_method("getNextMarker"),
+                    temp_path = temp_path_id
+                ))),
+                definite: false,
+            });
+            return (expr_id, Some(Expr::Ident(content_id).into()));
+        }
         let init = if index == 0 {
             quote!("$temp_path.firstChild" as Expr, temp_path = temp_path_id)
         } else {
@@ -1493,3 +1800,42 @@ where
         last_element
     }
 }
+
+/// Splits a `[handler, data]` event binding, reporting arrays with holes or
+/// spreads at `span` since their handler can't be known statically.
+fn split_bound_handler(expr: &Expr, span: Span) -> Option<(Expr, Option<Expr>)> {
+    let Expr::Array(arr_lit) = expr else {
+        return None;
+    };
+    let mut elems = arr_lit.elems.iter().map(|elem| match elem {
+        Some(ExprOrSpread { spread: None, expr }) => Some(*expr.clone()),
+        _ => None,
+    });
+    let handler = elems.next().flatten();
+    let data = elems.next();
+    match (handler, data) {
+        (Some(handler), None) => Some((handler, None)),
+        (Some(handler), Some(Some(data))) => Some((handler, Some(data))),
+        _ => {
+            emit_error(
+                span,
+                "Event handler arrays must be `[handler, data]` without holes or spreads",
+            );
+            None
+        }
+    }
+}
+
+/// A template literal part holding `text` as is.
+fn make_tpl_element(text: &str, tail: bool) -> TplElement {
+    let raw = text
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${");
+    TplElement {
+        span: DUMMY_SP,
+        tail,
+        cooked: Some(text.into()),
+        raw: raw.into(),
+    }
+}
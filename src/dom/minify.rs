@@ -0,0 +1,265 @@
+use crate::shared::constants::{BOOLEANS, VOID_ELEMENTS};
+
+/// Elements whose content the HTML tokenizer reads as raw text.
+const RAW_TEXT_ELEMENTS: [&str; 5] = ["script", "style", "textarea", "title", "xmp"];
+
+/// Elements that implicitly close an open `<p>`.
+const CLOSES_P: [&str; 30] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+];
+
+/// Parents whose end doesn't let a `<p>` end tag be omitted.
+const KEEPS_P_OPEN: [&str; 7] = ["a", "audio", "del", "ins", "map", "noscript", "video"];
+
+type Attr<'a> = (&'a str, Option<&'a str>);
+
+enum Token<'a> {
+    Start { name: &'a str, attrs: Vec<Attr<'a>> },
+    End(&'a str),
+    Comment(&'a str),
+    Text(&'a str),
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '\x0c')
+}
+
+fn tokenize(template: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = template;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!") {
+            let end = if comment.starts_with("--") {
+                comment.find("-->").map(|i| i + 3)
+            } else {
+                comment.find('>').map(|i| i + 1)
+            }
+            .unwrap_or(comment.len());
+            tokens.push(Token::Comment(&rest[..end + 2]));
+            rest = &comment[end..];
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            tokens.push(Token::End(tag[..end].trim_end()));
+            rest = tag.get(end + 1..).unwrap_or_default();
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (name, attrs, after) = tokenize_start_tag(&rest[1..]);
+            tokens.push(Token::Start { name, attrs });
+            rest = after;
+            // raw text runs up to the matching end tag without being parsed
+            if RAW_TEXT_ELEMENTS.contains(&name) {
+                let end = rest.find(&format!("</{name}")).unwrap_or(rest.len());
+                if end > 0 {
+                    tokens.push(Token::Text(&rest[..end]));
+                }
+                rest = &rest[end..];
+            }
+        } else {
+            let first = rest.chars().next().map_or(0, char::len_utf8);
+            let end = rest[first..]
+                .find('<')
+                .map(|i| i + first)
+                .unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn tokenize_start_tag(tag: &str) -> (&str, Vec<Attr<'_>>, &str) {
+    let name_end = tag
+        .find(|c: char| is_space(c) || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    let name = &tag[..name_end];
+    let mut rest = &tag[name_end..];
+    let mut attrs = vec![];
+    loop {
+        rest = rest.trim_start_matches(|c: char| is_space(c) || c == '/');
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            rest = after;
+            break;
+        }
+        let first = rest.chars().next().map_or(0, char::len_utf8);
+        let key_end = rest[first..]
+            .find(|c: char| is_space(c) || c == '=' || c == '>' || c == '/')
+            .map(|i| i + first)
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = &rest[key_end..];
+        let Some(value) = rest.trim_start_matches(is_space).strip_prefix('=') else {
+            attrs.push((key, None));
+            continue;
+        };
+        let value = value.trim_start_matches(is_space);
+        let (value, after) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value[1..].find(quote).map(|i| i + 1).unwrap_or(value.len());
+                (&value[1..end], value.get(end + 1..).unwrap_or_default())
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| is_space(c) || c == '>')
+                    .unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            }
+        };
+        attrs.push((key, Some(value)));
+        rest = after;
+    }
+    (name, attrs, rest)
+}
+
+/// Whether the end tag of `name` can be left out when followed by `next`,
+/// per the optional end tag rules of the HTML spec. `parent` is the element
+/// containing `name`.
+fn can_omit_end_tag(name: &str, next: &Token, parent: Option<&str>) -> bool {
+    match next {
+        Token::Start { name: next, .. } => match name {
+            "li" => *next == "li",
+            "dt" | "dd" => matches!(*next, "dt" | "dd"),
+            "p" => CLOSES_P.contains(next),
+            "option" => matches!(*next, "option" | "optgroup" | "hr"),
+            "optgroup" => matches!(*next, "optgroup" | "hr"),
+            "tr" => *next == "tr",
+            "td" | "th" => matches!(*next, "td" | "th"),
+            "thead" | "tbody" => matches!(*next, "tbody" | "tfoot"),
+            "rt" | "rp" => matches!(*next, "rt" | "rp"),
+            _ => false,
+        },
+        Token::End(_) => match name {
+            "li" | "dd" | "option" | "optgroup" | "tr" | "td" | "th" | "tbody" | "tfoot" | "rt"
+            | "rp" => true,
+            "p" => parent.is_some_and(|p| !KEEPS_P_OPEN.contains(&p) && !p.contains('-')),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn write_attr(out: &mut String, key: &str, value: Option<&str>, foreign: bool) {
+    out.push(' ');
+    out.push_str(key);
+    let Some(value) = value else {
+        return;
+    };
+    if !foreign && BOOLEANS.contains(&key) && (value.is_empty() || value.eq_ignore_ascii_case(key))
+    {
+        return;
+    }
+    out.push('=');
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|c| is_space(c) || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`'))
+    {
+        out.push('"');
+        out.push_str(value);
+        out.push('"');
+    } else {
+        out.push_str(value);
+    }
+}
+
+/// Shrinks a DOM template without changing the tree it parses to: attribute
+/// quotes that aren't needed are dropped, boolean attributes lose their
+/// values, optional end tags are omitted and whitespace in text is collapsed,
+/// except inside `pre`, raw text elements and the `preserved` tags.
+pub fn minify_template(template: &str, preserved: &[String]) -> String {
+    let tokens = tokenize(template);
+    let mut out = String::with_capacity(template.len());
+    let mut open: Vec<&str> = vec![];
+
+    for (index, token) in tokens.iter().enumerate() {
+        let foreign = open.iter().any(|name| matches!(*name, "svg" | "math"));
+        match token {
+            Token::Start { name, attrs } => {
+                let foreign = foreign || matches!(*name, "svg" | "math");
+                out.push('<');
+                out.push_str(name);
+                for (key, value) in attrs {
+                    write_attr(&mut out, key, *value, foreign);
+                }
+                out.push('>');
+                if !VOID_ELEMENTS.contains(name) {
+                    open.push(name);
+                }
+            }
+            Token::End(name) => {
+                let position = open.iter().rposition(|open| open == name);
+                let parent = position.and_then(|i| i.checked_sub(1)).map(|i| open[i]);
+                // everything still open is closed at the end of the template
+                let trailing = tokens[index + 1..]
+                    .iter()
+                    .all(|t| matches!(t, Token::End(_)));
+                let omit =
+                    trailing || !foreign && can_omit_end_tag(name, &tokens[index + 1], parent);
+                if !omit {
+                    out.push_str("</");
+                    out.push_str(name);
+                    out.push('>');
+                }
+                if let Some(position) = position {
+                    open.truncate(position);
+                }
+            }
+            Token::Comment(comment) => out.push_str(comment),
+            Token::Text(text) => {
+                let raw = open.iter().any(|name| {
+                    *name == "pre"
+                        || RAW_TEXT_ELEMENTS.contains(name)
+                        || preserved.iter().any(|tag| tag == name)
+                });
+                if raw {
+                    out.push_str(text);
+                } else {
+                    let mut last_space = false;
+                    for c in text.chars() {
+                        if is_space(c) {
+                            if !last_space {
+                                out.push(' ');
+                            }
+                            last_space = true;
+                        } else {
+                            out.push(c);
+                            last_space = false;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    out
+}
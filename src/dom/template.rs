@@ -1,16 +1,23 @@
-use super::element::AttrOptions;
+use super::{element::AttrOptions, minify::minify_template};
 use crate::{
     TransformVisitor,
     shared::{
-        structs::{DynamicAttr, TemplateConstruction, TemplateInstantiation},
-        utils::{make_iife, make_var_declarator, IntoFirst},
+        structs::{DynamicAttr, SharedTemplate, TemplateConstruction, TemplateInstantiation},
+        utils::{
+            IntoFirst, ProgramItem, insert_after_imports, long_content_hash, make_iife,
+            make_var_declarator, with_span,
+        },
     },
 };
+use std::collections::HashMap;
 use swc_core::{
-    common::{DUMMY_SP, Span, comments::Comments},
+    common::{
+        DUMMY_SP, Span,
+        comments::{Comment, CommentKind, Comments},
+    },
     ecma::{
         ast::*,
-        utils::{ExprFactory, prepend_stmt, quote_ident},
+        utils::{ExprFactory, quote_ident},
     },
     quote,
 };
@@ -19,7 +26,7 @@ impl<C> TransformVisitor<C>
 where
     C: Comments,
 {
-    pub fn create_template(&mut self, mut result: TemplateInstantiation, wrap: bool) -> Expr {
+    pub fn create_template_dom(&mut self, mut result: TemplateInstantiation, wrap: bool) -> Expr {
         if let Some(id) = &result.id {
             let id = id.clone();
             self.register_template(&mut result);
@@ -28,7 +35,7 @@ where
                 && result.post_exprs.is_empty()
                 && result.declarations.len() == 1
             {
-                return *result.declarations.into_first().init.unwrap();
+                return with_span(*result.declarations.into_first().init.unwrap(), result.span);
             } else {
                 let stmts = [VarDecl {
                     kind: VarDeclKind::Const,
@@ -47,7 +54,7 @@ where
                 .chain(result.post_exprs.into_iter().map(|x| x.into_stmt()))
                 .chain([id.into_return_stmt().into()])
                 .collect();
-                return make_iife(stmts);
+                return with_span(make_iife(stmts), result.span);
             }
         }
 
@@ -64,14 +71,18 @@ where
         expr
     }
 
-    pub fn append_templates(&mut self, module: &mut Module) {
+    pub fn append_templates_dom<T: ProgramItem>(&mut self, body: &mut Vec<T>) {
         if self.templates.is_empty() {
             return;
         }
+        if !self.config.shared_templates.is_empty() {
+            self.import_shared_templates();
+            return;
+        }
         let templ = self.register_import_method("template");
-        prepend_stmt(
-            &mut module.body,
-            ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        insert_after_imports(
+            body,
+            T::from(Stmt::Decl(Decl::Var(Box::new(VarDecl {
                 span: DUMMY_SP,
                 kind: VarDeclKind::Const,
                 declare: false,
@@ -115,35 +126,117 @@ where
         )
     }
 
+    /// Imports the templates from the `sharedTemplates` module instead of
+    /// declaring them, naming each export after its content so every file
+    /// using the same markup shares one declaration.
+    fn import_shared_templates(&mut self) {
+        let module_name = self.config.shared_templates.clone();
+        for template in std::mem::take(&mut self.templates) {
+            let name = format!(
+                "tmpl_{}",
+                long_content_hash(&[
+                    &template.template,
+                    &template.is_ce.to_string(),
+                    &template.is_svg.to_string()
+                ])
+            );
+            if !self.shared_templates.iter().any(|t| t.name == name) {
+                self.shared_templates.push(SharedTemplate {
+                    name: name.clone(),
+                    template: template.template,
+                    is_svg: template.is_svg,
+                    is_ce: template.is_ce,
+                });
+            }
+            self.imports.insert((module_name.clone(), name), template.id);
+        }
+    }
+
+    /// Puts the shared templates imported from `source` under `names` in a
+    /// leading comment on `span`, which plugin hosts collect from the output
+    /// to build the module with `render_shared_templates`.
+    pub fn annotate_shared_templates(&mut self, source: &str, names: &[String], span: Span) -> Span {
+        if self.config.shared_templates != source {
+            return span;
+        }
+        let templates: Vec<&SharedTemplate> = self
+            .shared_templates
+            .iter()
+            .filter(|template| names.contains(&template.name))
+            .collect();
+        if templates.is_empty() {
+            return span;
+        }
+        // `*/` can only appear inside JSON strings, where `\/` is a valid escape
+        let json = serde_json::to_string(&templates)
+            .expect("shared templates serialize")
+            .replace("*/", "*\\/");
+        let span = if span.is_dummy() {
+            Span::dummy_with_cmt()
+        } else {
+            span
+        };
+        self.comments.add_leading(
+            span.lo,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: format!(" {SHARED_TEMPLATES_PRAGMA} {json} ").into(),
+            },
+        );
+        span
+    }
+
     pub fn register_template(&mut self, results: &mut TemplateInstantiation) {
+        if self.config.minify_templates {
+            results.template =
+                minify_template(&results.template, &self.config.whitespace_sensitive_tags);
+        }
         if !results.template.is_empty() {
-            let template_id: Ident;
+            let mut template_id = None;
             if !results.skip_template {
                 let template_def = self
                     .templates
                     .iter()
                     .find(|t| t.template == results.template);
                 if let Some(template_def) = template_def {
-                    template_id = template_def.id.clone();
+                    template_id = Some(template_def.id.clone());
                 } else {
-                    template_id = self.generate_uid_identifier("tmpl$");
+                    let id = self.generate_template_identifier(&results.template);
                     self.templates.push(TemplateConstruction {
-                        id: template_id.clone(),
+                        id: id.clone(),
                         template: results.template.clone(),
+                        template_parts: vec![],
                         is_svg: results.is_svg,
                         is_ce: results.has_custom_element,
                     });
+                    template_id = Some(id);
                 }
+            }
 
-                let decl = VarDeclarator {
+            let init = if self.config.hydratable {
+                Expr::Call(CallExpr {
                     span: DUMMY_SP,
-                    name: Pat::Ident(results.id.clone().unwrap().into()),
-                    init: quote!("$tpl()" as Option<Box<Expr>>, tpl = template_id),
-                    definite: false,
-                };
+                    callee: Callee::Expr(Box::new(Expr::Ident(
+                        self.register_import_method("getNextElement"),
+                    ))),
+                    args: template_id.into_iter().map(|id| Expr::Ident(id).into()).collect(),
+                    ..Default::default()
+                })
+            } else if let Some(template_id) = template_id {
+                quote!("$tpl()" as Expr, tpl = template_id)
+            } else {
+                return;
+            };
 
-                results.declarations.insert(0, decl);
-            }
+            let decl = VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(results.id.clone().unwrap().into()),
+                init: Some(Box::new(init)),
+                definite: false,
+            };
+
+            results.declarations.insert(0, decl);
         }
     }
 
@@ -182,7 +275,8 @@ where
                     tag_name: attr.tag_name.clone(),
                 },
             );
-            return Some(vec![if let Some(prev_value) = prev_value {
+            let my_set_attr = with_span(my_set_attr, attr.span);
+            let effect = if let Some(prev_value) = prev_value {
                 quote!("$effect_wrapper(($params) => $my_set_attr)" as Expr,
                     effect_wrapper = effect_wrapper_id,
                     params: Pat = prev_value.into(),
@@ -193,13 +287,15 @@ where
                     effect_wrapper = effect_wrapper_id,
                     my_set_attr: Expr = my_set_attr
                 )
-            }]);
+            };
+            return Some(vec![with_span(effect, attr.span)]);
         }
 
         let mut decls = vec![];
         let mut statements = vec![];
         let mut identifiers = vec![];
         let prev_id = Ident::new_no_ctxt("_p$".into(), DUMMY_SP);
+        let span = dynamics[0].span;
 
         for mut attr in dynamics {
             let identifier = self.generate_uid_identifier("v$");
@@ -210,7 +306,10 @@ where
                 attr.value = quote!("!!$x" as Expr, x: Expr = attr.value);
             }
             identifiers.push(identifier.clone());
-            decls.push(make_var_declarator(identifier.clone(), attr.value));
+            decls.push(VarDeclarator {
+                span: attr.span,
+                ..make_var_declarator(identifier.clone(), attr.value)
+            });
 
             if attr.key == "classList" || attr.key == "style" {
                 let prev = MemberExpr {
@@ -305,10 +404,58 @@ where
                 })
                 .collect(),
         });
-        Some(vec![quote!("$effect_wrapper($my_fn, $obj)" as Expr,
-            effect_wrapper = effect_wrapper_id,
-            my_fn: Expr = effect_fn,
-            obj: Expr = effect_obj
+        Some(vec![with_span(
+            quote!("$effect_wrapper($my_fn, $obj)" as Expr,
+                effect_wrapper = effect_wrapper_id,
+                my_fn: Expr = effect_fn,
+                obj: Expr = effect_obj
+            ),
+            span,
         )])
     }
 }
+
+/// Starts the comment listing the shared templates a transformed file imports.
+pub const SHARED_TEMPLATES_PRAGMA: &str = "@shared-templates";
+
+/// Reads the templates back from the text of a comment added by
+/// `annotate_shared_templates`, or `None` for any other comment.
+pub fn read_shared_templates(comment: &str) -> Option<Vec<SharedTemplate>> {
+    let json = comment.trim().strip_prefix(SHARED_TEMPLATES_PRAGMA)?;
+    serde_json::from_str(json).ok()
+}
+
+/// Source of the `sharedTemplates` module declaring `templates`, with the
+/// `template` helper imported from `module_name`. Templates collected from
+/// several files may repeat, but one name can't stand for two templates.
+pub fn render_shared_templates(
+    templates: &[SharedTemplate],
+    module_name: &str,
+) -> Result<String, String> {
+    // a JSON string is also a valid JavaScript string literal
+    let module_name = serde_json::to_string(module_name).map_err(|err| err.to_string())?;
+    let mut source = format!("import {{ template }} from {module_name};\n");
+    let mut declared: HashMap<&str, &SharedTemplate> = HashMap::new();
+    for template in templates {
+        match declared.insert(&template.name, template) {
+            Some(existing) if existing == template => continue,
+            Some(existing) => {
+                return Err(format!(
+                    "Shared template `{}` is declared as both `{}` and `{}`",
+                    template.name, existing.template, template.template
+                ));
+            }
+            None => {}
+        }
+        let flags = if template.is_svg || template.is_ce {
+            format!(", {}, {}", template.is_ce, template.is_svg)
+        } else {
+            String::new()
+        };
+        source += &format!(
+            "export const {} = /*#__PURE__*/ template(`{}`{flags});\n",
+            template.name, template.template
+        );
+    }
+    Ok(source)
+}
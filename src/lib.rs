@@ -1,12 +1,12 @@
-use shared::transform::ThisBlockVisitor;
+use shared::transform::{ThisBlockVisitor, contains_jsx};
 use swc_core::{
-    common::{comments::Comments, util::take::Take},
+    common::{comments::Comments, sync::Lrc, util::take::Take},
     ecma::{
         ast::*,
-        minifier::{eval::Evaluator, marks::Marks},
         visit::{VisitMut, VisitMutWith, VisitWith},
     },
     plugin::{
+        metadata::TransformPluginMetadataContextKind,
         plugin_transform,
         proxies::{PluginCommentsProxy, TransformPluginProgramMetadata},
     },
@@ -15,7 +15,12 @@ use swc_core::{
 pub mod config;
 mod dom;
 mod shared;
-pub use crate::shared::structs::TransformVisitor;
+mod ssr;
+mod universal;
+pub use crate::dom::template::{
+    SHARED_TEMPLATES_PRAGMA, read_shared_templates, render_shared_templates,
+};
+pub use crate::shared::structs::{SharedTemplate, TransformVisitor};
 
 impl<C> VisitMut for TransformVisitor<C>
 where
@@ -39,15 +44,72 @@ where
         expr.visit_mut_children_with(self);
     }
     fn visit_mut_module(&mut self, module: &mut Module) {
-        self.evaluator = Some(Evaluator::new(module.clone(), Marks::new()));
+        let config = self.config.clone();
+        self.apply_config_pragma(module.span, &module.body);
+        self.transform_module(module);
+        self.config = config;
+    }
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        let config = self.config.clone();
+        self.apply_config_pragma(script.span, &script.body);
+        self.transform_script(script);
+        self.config = config;
+    }
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    fn transform_module(&mut self, module: &mut Module) {
+        if let Some(source) = self
+            .config
+            .require_import_source
+            .source(&self.config.module_name)
+            && self
+                .get_jsx_import_source(module.span, &module.body)
+                .as_deref()
+                != Some(source)
+        {
+            return;
+        }
+        if !contains_jsx(module) {
+            return;
+        }
+        self.collect_existing_imports(module);
+        self.collect_static_bindings(module);
         module.visit_mut_children_with(&mut ThisBlockVisitor::new());
         module.visit_children_with(&mut self.binding_collector);
         module.visit_mut_children_with(self);
 
-        self.append_templates(module);
-        self.insert_events(module);
+        self.append_templates(&mut module.body);
+        self.insert_events(&mut module.body);
+        self.insert_hot_registrations(module);
         self.insert_imports(module);
     }
+    fn transform_script(&mut self, script: &mut Script) {
+        if let Some(source) = self
+            .config
+            .require_import_source
+            .source(&self.config.module_name)
+            && self
+                .get_jsx_import_source(script.span, &script.body)
+                .as_deref()
+                != Some(source)
+        {
+            return;
+        }
+        if !contains_jsx(script) {
+            return;
+        }
+        script.visit_mut_children_with(&mut ThisBlockVisitor::new());
+        script.visit_children_with(&mut self.binding_collector);
+        script.visit_mut_children_with(self);
+
+        self.append_templates(&mut script.body);
+        self.insert_events(&mut script.body);
+        self.insert_requires(&mut script.body);
+    }
 }
 
 #[plugin_transform]
@@ -55,12 +117,22 @@ pub fn process_transform(
     mut program: Program,
     metadata: TransformPluginProgramMetadata,
 ) -> Program {
-    let config: config::Config = metadata
-        .get_transform_plugin_config()
-        .and_then(|json| serde_json::from_str(&json).ok())
-        .unwrap_or_default();
+    let config: config::Config = match metadata.get_transform_plugin_config() {
+        Some(json) => config::Config::from_json(&json).unwrap_or_else(|err| panic!("{err}")),
+        None => Default::default(),
+    };
 
-    program.visit_mut_with(&mut TransformVisitor::new(config, PluginCommentsProxy));
+    let filename = metadata.get_context(&TransformPluginMetadataContextKind::Filename);
+    if let Some(filename) = &filename {
+        let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+        if !config.should_transform_in(filename, cwd.as_deref()) {
+            return program;
+        }
+    }
+    program.visit_mut_with(
+        &mut TransformVisitor::new(config, PluginCommentsProxy)
+            .with_source_map(filename, Lrc::new(metadata.source_map)),
+    );
 
     program
 }
@@ -1,6 +1,9 @@
 pub mod component;
 pub mod constants;
+pub mod evaluate;
 pub mod fragment;
+pub mod hmr;
 pub mod structs;
 pub mod transform;
 pub mod utils;
+pub mod validate;
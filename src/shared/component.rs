@@ -2,11 +2,12 @@ use super::{
     structs::TemplateInstantiation,
     transform::TransformInfo,
     utils::{
-        convert_jsx_identifier, filter_children, jsx_text_to_str, make_const_var_decl,
-        make_getter_prop, make_iife, make_return_block, unwrap_ts_expr, IntoFirst,
+        convert_jsx_identifier, emit_error, filter_children, get_tag_name, jsx_text_to_str,
+        make_const_var_decl, make_getter_prop, make_iife, make_return_block, unwrap_arrow_body,
+        unwrap_ts_expr, with_span, IntoFirst,
     },
 };
-use crate::{TransformVisitor, shared::utils::is_l_val};
+use crate::{TransformVisitor, config::Generate, shared::utils::is_l_val};
 use swc_core::{
     common::{DUMMY_SP, comments::Comments},
     ecma::{
@@ -21,6 +22,8 @@ where
     C: Comments,
 {
     pub fn transform_component(&mut self, node: JSXElement) -> TemplateInstantiation {
+        let span = node.span;
+        let dev_info = self.config.dev.then(|| self.make_dev_info(&node));
         let mut exprs: Vec<Expr> = vec![];
         let mut tag_id = get_component_identifier(&node.opening.name);
         let mut props = vec![];
@@ -118,14 +121,7 @@ where
                                         || matches!(*expr, Expr::Cond(_)))
                                 {
                                     (_, exp) = self.transform_condition(*expr, true, false);
-                                    if let Expr::Arrow(ArrowExpr { body, .. }) = exp {
-                                        match *body {
-                                            BlockStmtOrExpr::Expr(ex) => exp = *ex,
-                                            BlockStmtOrExpr::BlockStmt(_) => panic!(),
-                                        }
-                                    } else {
-                                        panic!()
-                                    }
+                                    exp = unwrap_arrow_body(exp);
                                 } else {
                                     exp = *expr;
                                 }
@@ -224,11 +220,23 @@ where
         }
 
         let create_component = self.register_import_method("createComponent");
-        let component_call = quote!(
-            "$create_component($tag, $props)" as Expr,
-            create_component = create_component,
-            tag: Expr = tag_id,
-            props: Expr = props.remove(0)
+        let component_call = with_span(
+            match dev_info {
+                Some(dev_info) => quote!(
+                    "$create_component($tag, $props, $dev_info)" as Expr,
+                    create_component = create_component,
+                    tag: Expr = tag_id,
+                    props: Expr = props.remove(0),
+                    dev_info: Expr = dev_info
+                ),
+                None => quote!(
+                    "$create_component($tag, $props)" as Expr,
+                    create_component = create_component,
+                    tag: Expr = tag_id,
+                    props: Expr = props.remove(0)
+                ),
+            },
+            span,
         );
 
         if exprs.is_empty() {
@@ -246,6 +254,26 @@ where
         }
     }
 
+    /// `{ name, location }` passed to `createComponent` in `dev` mode so
+    /// devtools can show the component and where it was rendered from.
+    fn make_dev_info(&self, node: &JSXElement) -> Expr {
+        let mut props = vec![Prop::KeyValue(KeyValueProp {
+            key: quote_ident!("name").into(),
+            value: Box::new(get_tag_name(node).into()),
+        })];
+        if let Some(location) = self.source_location(node.span) {
+            props.push(Prop::KeyValue(KeyValueProp {
+                key: quote_ident!("location").into(),
+                value: Box::new(location.into()),
+            }));
+        }
+        ObjectLit {
+            span: DUMMY_SP,
+            props: props.into_iter().map(|p| p.into()).collect(),
+        }
+        .into()
+    }
+
     fn transform_component_children(
         &mut self,
         children: Vec<JSXElementChild>,
@@ -299,7 +327,7 @@ where
                             if let Some(mut child) = child {
                                 dynamic = dynamic || child.dynamic;
 
-                                if self.config.generate == "ssr"
+                                if self.config.generate == Generate::Ssr
                                     && is_filtered_children_plural
                                     && child.dynamic
                                     && let Some(Expr::Arrow(ArrowExpr { body, .. })) =
@@ -407,7 +435,10 @@ fn get_component_identifier(node: &JSXElementName) -> Expr {
                 },
             })
         }
-        JSXElementName::JSXNamespacedName(_) => panic!("Can't handle this"),
+        JSXElementName::JSXNamespacedName(name) => {
+            emit_error(name.span, "Namespaced tag names can't be used as components");
+            Expr::Lit(Lit::Str(format!("{}:{}", name.ns.sym, name.name.sym).into()))
+        }
     }
 }
 
@@ -0,0 +1,270 @@
+use super::utils::{lit_to_string, number_to_string, unwrap_ts_expr};
+use crate::TransformVisitor;
+use swc_core::{common::comments::Comments, ecma::ast::*};
+
+/// How many `const` bindings are followed before giving up on a value.
+const MAX_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalResult {
+    Lit(Lit),
+    Undefined,
+}
+
+pub fn is_truthy(lit: &Lit) -> bool {
+    match lit {
+        Lit::Str(s) => !s.value.is_empty(),
+        Lit::Num(n) => n.value != 0.0 && !n.value.is_nan(),
+        Lit::Bool(b) => b.value,
+        Lit::Null(_) => false,
+        Lit::BigInt(b) => b.value.to_string() != "0",
+        _ => true,
+    }
+}
+
+fn to_js_string(result: &EvalResult) -> String {
+    match result {
+        EvalResult::Lit(lit) => lit_to_string(lit),
+        EvalResult::Undefined => "undefined".to_string(),
+    }
+}
+
+/// The name of a property key, or `None` when it is computed.
+fn prop_key(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(key) => Some(key.sym.to_string()),
+        PropName::Str(key) => Some(key.value.to_string_lossy().into_owned()),
+        PropName::Num(key) => Some(number_to_string(key.value)),
+        _ => None,
+    }
+}
+
+fn number(value: f64) -> Option<EvalResult> {
+    Some(EvalResult::Lit(Lit::Num(value.into())))
+}
+
+fn string(value: String) -> Option<EvalResult> {
+    Some(EvalResult::Lit(Lit::Str(value.into())))
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Folds `expr` to a literal when it is built only from literals and
+    /// `const` bindings with constant initializers, as found by
+    /// `VarBindingCollector`. `let` and `var` bindings are followed too when
+    /// they are never assigned again and are read after their declaration.
+    pub fn eval(&self, expr: &Expr) -> Option<EvalResult> {
+        self.eval_with_depth(expr, 0)
+    }
+
+    /// The object literal a `const` binding like `const STYLE = { color: RED }`
+    /// refers to, with each value folded, so `style={STYLE}` can be baked into
+    /// the template. Only objects whose every value folds are inlined, keeping
+    /// the last value of a repeated key.
+    pub fn eval_object(&self, expr: &Expr) -> Option<ObjectLit> {
+        let Expr::Ident(id) = unwrap_ts_expr(expr.clone()) else {
+            return None;
+        };
+        let object = self.const_object(&id)?;
+        let entries = object
+            .props
+            .iter()
+            .map(|prop| {
+                let PropOrSpread::Prop(prop) = prop else {
+                    return None;
+                };
+                let Prop::KeyValue(KeyValueProp { key, value }) = &**prop else {
+                    return None;
+                };
+                if !matches!(key, PropName::Ident(_) | PropName::Str(_)) {
+                    return None;
+                }
+                Some((prop_key(key)?, key, value))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let props = entries
+            .iter()
+            .enumerate()
+            .filter(|(index, (name, ..))| !entries[index + 1..].iter().any(|(n, ..)| n == name))
+            .map(|(_, (_, key, value))| {
+                let EvalResult::Lit(value) = self.eval(value)? else {
+                    return None;
+                };
+                Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: (*key).clone(),
+                    value: Box::new(value.into()),
+                }))))
+            })
+            .collect::<Option<_>>()?;
+        Some(ObjectLit {
+            span: object.span,
+            props,
+        })
+    }
+
+    /// The object literal `id` is initialized with, unless the object is
+    /// written to or used other than by reading its properties.
+    fn const_object(&self, id: &Ident) -> Option<ObjectLit> {
+        if self.binding_collector.mutated.contains(&id.to_id()) {
+            return None;
+        }
+        match unwrap_ts_expr(self.binding_init(id)??.clone()) {
+            Expr::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// The initializer of a `const` binding, or of a `let`/`var` binding that
+    /// is never assigned again, when `id` reads it after the declaration.
+    fn binding_init(&self, id: &Ident) -> Option<Option<&Expr>> {
+        let bindings = &self.binding_collector;
+        let id_key = id.to_id();
+        if let Some(init) = bindings.const_var_bindings.get(&id_key) {
+            return Some(init.as_ref());
+        }
+        // a `var` read before its declaration is still `undefined`
+        bindings
+            .let_var_bindings
+            .get(&id_key)
+            .filter(|(declared, _)| !bindings.reassigned.contains(&id_key) && id.span.lo >= *declared)
+            .map(|(_, init)| init.as_ref())
+    }
+
+    fn eval_with_depth(&self, expr: &Expr, depth: usize) -> Option<EvalResult> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let eval = |expr: &Expr| self.eval_with_depth(expr, depth + 1);
+        match expr {
+            Expr::Lit(
+                lit @ (Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_) | Lit::BigInt(_)),
+            ) => Some(EvalResult::Lit(lit.clone())),
+            Expr::Paren(ParenExpr { expr, .. }) => eval(expr),
+            Expr::Seq(SeqExpr { exprs, .. }) => {
+                let (last, rest) = exprs.split_last()?;
+                // earlier expressions are only dropped when they fold themselves,
+                // so calls and assignments are never lost
+                if rest.iter().any(|expr| eval(expr).is_none()) {
+                    return None;
+                }
+                eval(last)
+            }
+            Expr::TsAs(_)
+            | Expr::TsSatisfies(_)
+            | Expr::TsConstAssertion(_)
+            | Expr::TsNonNull(_) => eval(&unwrap_ts_expr(expr.clone())),
+            Expr::Ident(id) => match self.binding_init(id) {
+                Some(Some(init)) => eval(init),
+                Some(None) => None,
+                None if &*id.sym == "undefined" => Some(EvalResult::Undefined),
+                None => None,
+            },
+            Expr::Tpl(Tpl { exprs, quasis, .. }) => {
+                let mut value = String::new();
+                for (index, quasi) in quasis.iter().enumerate() {
+                    value += &quasi.cooked.as_ref()?.to_string_lossy();
+                    if let Some(expr) = exprs.get(index) {
+                        value += &to_js_string(&eval(expr)?);
+                    }
+                }
+                string(value)
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(IdentName { sym: prop, .. }),
+                ..
+            }) => {
+                let Expr::Ident(obj) = unwrap_ts_expr(*obj.clone()) else {
+                    return None;
+                };
+                let object = self.const_object(&obj)?;
+                // the last definition wins, and a spread may hold any key
+                for prop_or_spread in object.props.iter().rev() {
+                    let PropOrSpread::Prop(prop_def) = prop_or_spread else {
+                        return None;
+                    };
+                    match &**prop_def {
+                        Prop::KeyValue(KeyValueProp { key, value }) => {
+                            if prop_key(key)? == prop.as_str() {
+                                return eval(value);
+                            }
+                        }
+                        Prop::Shorthand(key) if key.sym == *prop => {
+                            return eval(&Expr::Ident(key.clone()));
+                        }
+                        Prop::Shorthand(_) => {}
+                        _ => return None,
+                    }
+                }
+                None
+            }
+            Expr::Unary(UnaryExpr { op, arg, .. }) => {
+                let arg = eval(arg)?;
+                match (op, arg) {
+                    (UnaryOp::Void, _) => Some(EvalResult::Undefined),
+                    (UnaryOp::Bang, EvalResult::Lit(lit)) => {
+                        Some(EvalResult::Lit(Lit::Bool((!is_truthy(&lit)).into())))
+                    }
+                    (UnaryOp::Bang, EvalResult::Undefined) => {
+                        Some(EvalResult::Lit(Lit::Bool(true.into())))
+                    }
+                    (UnaryOp::Minus, EvalResult::Lit(Lit::Num(n))) => number(-n.value),
+                    (UnaryOp::Plus, EvalResult::Lit(Lit::Num(n))) => number(n.value),
+                    _ => None,
+                }
+            }
+            Expr::Bin(BinExpr {
+                op, left, right, ..
+            }) => {
+                let left = eval(left)?;
+                match op {
+                    BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+                        let truthy = match &left {
+                            EvalResult::Lit(lit) => is_truthy(lit),
+                            EvalResult::Undefined => false,
+                        };
+                        if truthy == (*op == BinaryOp::LogicalAnd) {
+                            eval(right)
+                        } else {
+                            Some(left)
+                        }
+                    }
+                    BinaryOp::NullishCoalescing => match left {
+                        EvalResult::Lit(Lit::Null(_)) | EvalResult::Undefined => eval(right),
+                        left => Some(left),
+                    },
+                    _ => match (op, left, eval(right)?) {
+                        (
+                            BinaryOp::Add,
+                            EvalResult::Lit(Lit::Num(left)),
+                            EvalResult::Lit(Lit::Num(right)),
+                        ) => number(left.value + right.value),
+                        (BinaryOp::Add, left @ EvalResult::Lit(Lit::Str(_)), right)
+                        | (BinaryOp::Add, left, right @ EvalResult::Lit(Lit::Str(_))) => {
+                            string(format!("{}{}", to_js_string(&left), to_js_string(&right)))
+                        }
+                        (op, EvalResult::Lit(Lit::Num(left)), EvalResult::Lit(Lit::Num(right))) => {
+                            match op {
+                                BinaryOp::Sub => number(left.value - right.value),
+                                BinaryOp::Mul => number(left.value * right.value),
+                                BinaryOp::Div => number(left.value / right.value),
+                                BinaryOp::Mod => number(left.value % right.value),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    },
+                }
+            }
+            Expr::Cond(CondExpr {
+                test, cons, alt, ..
+            }) => match eval(test)? {
+                EvalResult::Lit(lit) if is_truthy(&lit) => eval(cons),
+                _ => eval(alt),
+            },
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use super::utils::{insert_after_imports, make_const_var_decl, make_var_declarator};
+use crate::{TransformVisitor, config::Hmr};
+use swc_core::{
+    common::{DUMMY_SP, Span, comments::Comments},
+    ecma::{
+        ast::*,
+        utils::{ExprFactory, private_ident, quote_ident},
+    },
+    quote,
+};
+
+/// Top-level functions named in PascalCase are treated as components.
+fn is_component_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn is_function(expr: &Expr) -> bool {
+    matches!(expr, Expr::Fn(_) | Expr::Arrow(_))
+}
+
+/// `module.hot` for standard webpack HMR, `import.meta.webpackHot` for webpack
+/// and rspack ESM, and `import.meta.hot` for Vite and ESM HMR.
+fn hot_api(hmr: Hmr) -> Expr {
+    let prop = match hmr {
+        Hmr::Standard => return quote!("module.hot" as Expr),
+        Hmr::Webpack5 | Hmr::RspackEsm => "webpackHot",
+        Hmr::Esm | Hmr::Vite => "hot",
+    };
+    MetaPropExpr {
+        span: DUMMY_SP,
+        kind: MetaPropKind::ImportMeta,
+    }
+    .make_member(quote_ident!(prop))
+    .into()
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Registers the top-level components of the module with a `solid-refresh`
+    /// style registry and appends the bundler's `accept` glue, so edited
+    /// components are swapped in place during development.
+    pub fn insert_hot_registrations(&mut self, module: &mut Module) {
+        let Some(hmr) = self.config.hmr.filter(|_| self.config.dev) else {
+            return;
+        };
+        let registry = private_ident!("_REGISTRY");
+        let mut registered = false;
+        let mut body = Vec::with_capacity(module.body.len());
+
+        for item in std::mem::take(&mut module.body) {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(decl)))
+                    if is_component_name(&decl.ident.sym) =>
+                {
+                    registered = true;
+                    let registration =
+                        self.register_fn_decl(&registry, &decl.ident, decl.function.span);
+                    body.push(Stmt::Decl(Decl::Fn(decl)).into());
+                    body.push(registration.into());
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(mut decl))) => {
+                    registered |= self.wrap_var_decl(&registry, &mut decl);
+                    body.push(decl.into());
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Fn(decl),
+                })) if is_component_name(&decl.ident.sym) => {
+                    registered = true;
+                    let registration =
+                        self.register_fn_decl(&registry, &decl.ident, decl.function.span);
+                    body.push(
+                        ModuleDecl::ExportDecl(ExportDecl {
+                            span,
+                            decl: Decl::Fn(decl),
+                        })
+                        .into(),
+                    );
+                    body.push(registration.into());
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Var(mut decl),
+                })) => {
+                    registered |= self.wrap_var_decl(&registry, &mut decl);
+                    body.push(
+                        ModuleDecl::ExportDecl(ExportDecl {
+                            span,
+                            decl: Decl::Var(decl),
+                        })
+                        .into(),
+                    );
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl:
+                        DefaultDecl::Fn(FnExpr {
+                            ident: Some(ref ident),
+                            ref function,
+                        }),
+                    ..
+                })) if is_component_name(&ident.sym) => {
+                    // the default export is bound to `App`, so it sees the reassignment too
+                    registered = true;
+                    let registration = self.register_fn_decl(&registry, ident, function.span);
+                    body.push(item);
+                    body.push(registration.into());
+                }
+                item => body.push(item),
+            }
+        }
+        module.body = body;
+
+        if !registered {
+            return;
+        }
+
+        let registry_fn =
+            self.register_import_from(self.config.hmr_module_name.clone(), "$$registry");
+        insert_after_imports(
+            &mut module.body,
+            make_const_var_decl(
+                registry.clone(),
+                quote!("$registry_fn()" as Expr, registry_fn = registry_fn),
+            )
+            .into(),
+        );
+
+        let refresh = self.register_import_from(self.config.hmr_module_name.clone(), "$$refresh");
+        module.body.push(ModuleItem::Stmt(quote!(
+            "if ($hot) { $refresh($kind, $hot, $registry); $hot.accept(); }" as Stmt,
+            hot: Expr = hot_api(hmr),
+            refresh = refresh,
+            kind: Expr = hmr.as_str().into(),
+            registry = registry
+        )));
+    }
+
+    /// Function declarations stay in place so they are still hoisted, and are
+    /// swapped for the registered component by a following `App = ...`.
+    fn register_fn_decl(&mut self, registry: &Ident, ident: &Ident, span: Span) -> Stmt {
+        let component = self.wrap_component(registry, &ident.sym, ident.clone().into(), span);
+        quote!(
+            "$name = $component;" as Stmt,
+            name = ident.clone(),
+            component: Expr = component
+        )
+    }
+
+    /// Wraps `const App = () => ...` style declarations, returning whether any
+    /// component was found.
+    fn wrap_var_decl(&mut self, registry: &Ident, decl: &mut VarDecl) -> bool {
+        let mut registered = false;
+        for declarator in decl.decls.iter_mut() {
+            let Pat::Ident(name) = &declarator.name else {
+                continue;
+            };
+            if !is_component_name(&name.sym) || !declarator.init.as_deref().is_some_and(is_function)
+            {
+                continue;
+            }
+            let name = name.id.clone();
+            let init = *declarator.init.take().unwrap();
+            let span = declarator.span;
+            *declarator = VarDeclarator {
+                span,
+                ..make_var_declarator(
+                    name.clone(),
+                    self.wrap_component(registry, &name.sym, init, span),
+                )
+            };
+            registered = true;
+        }
+        registered
+    }
+
+    fn wrap_component(
+        &mut self,
+        registry: &Ident,
+        name: &str,
+        component: Expr,
+        span: Span,
+    ) -> Expr {
+        let component_fn =
+            self.register_import_from(self.config.hmr_module_name.clone(), "$$component");
+        let mut args = vec![registry.clone().as_arg(), name.as_arg(), component.as_arg()];
+        if let Some(location) = self.source_location(span) {
+            args.push(
+                ObjectLit {
+                    span: DUMMY_SP,
+                    props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: quote_ident!("location").into(),
+                        value: Box::new(location.into()),
+                    })))],
+                }
+                .as_arg(),
+            );
+        }
+        CallExpr {
+            span: DUMMY_SP,
+            callee: component_fn.as_callee(),
+            args,
+            ..Default::default()
+        }
+        .into()
+    }
+}
@@ -1,21 +1,34 @@
-use super::transform::VarBindingCollector;
+use super::{transform::VarBindingCollector, utils::content_hash};
 use crate::config::Config;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
 };
 use swc_core::{
-    common::comments::Comments,
-    ecma::{ast::*, minifier::eval::{EvalResult, Evaluator}, utils::private_ident},
+    common::{Span, comments::Comments, errors::SourceMapperDyn, sync::Lrc},
+    ecma::{ast::*, utils::private_ident},
 };
 
 pub struct TemplateConstruction {
     pub template: String,
+    pub template_parts: Vec<String>,
     pub id: Ident,
     pub is_svg: bool,
     pub is_ce: bool,
 }
 
+/// A DOM template imported from the `sharedTemplates` module. The bundler
+/// declares each one once in that module, see `render_shared_templates`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedTemplate {
+    pub name: String,
+    pub template: String,
+    pub is_svg: bool,
+    pub is_ce: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct DynamicAttr {
     pub elem: Ident,
@@ -24,12 +37,23 @@ pub struct DynamicAttr {
     pub is_svg: bool,
     pub is_ce: bool,
     pub tag_name: String,
+    pub span: Span,
+}
+
+/// The renderer an element is compiled for in `generate: "dynamic"` mode:
+/// the DOM backend, or the universal one importing from `module_name`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Renderer {
+    pub dom: bool,
+    pub module_name: String,
 }
 
 #[derive(Debug, Default)]
 pub struct TemplateInstantiation {
     pub component: bool,
     pub template: String,
+    pub template_parts: Vec<String>,
+    pub template_values: Vec<Expr>,
     pub declarations: Vec<VarDeclarator>,
     pub id: Option<Ident>,
     pub tag_name: String,
@@ -39,10 +63,15 @@ pub struct TemplateInstantiation {
     pub is_svg: bool,
     pub is_void: bool,
     pub has_custom_element: bool,
+    pub has_hydratable_event: bool,
     pub text: bool,
     pub dynamic: bool,
     pub to_be_closed: Option<HashSet<String>>,
+    pub preserve_whitespace: bool,
+    pub do_not_escape: bool,
     pub skip_template: bool,
+    pub renderer: Option<Renderer>,
+    pub span: Span,
 }
 
 pub struct TransformVisitor<C>
@@ -52,11 +81,16 @@ where
     pub config: Config,
     pub template: Option<TemplateInstantiation>,
     pub templates: Vec<TemplateConstruction>,
-    pub imports: HashMap<String, Ident>,
+    pub imports: HashMap<(String, String), Ident>,
+    pub existing_imports: HashMap<(String, String), Ident>,
+    pub static_bindings: HashSet<Id>,
     pub events: HashSet<String>,
     pub comments: C,
-    pub evaluator: Option<Evaluator>,
     pub binding_collector: VarBindingCollector,
+    pub renderer: Option<Renderer>,
+    pub shared_templates: Vec<SharedTemplate>,
+    pub filename: Option<String>,
+    pub source_map: Option<Lrc<SourceMapperDyn>>,
     uid_identifier_map: HashMap<String, usize>,
 }
 
@@ -70,14 +104,31 @@ where
             templates: vec![],
             template: None,
             imports: Default::default(),
+            existing_imports: Default::default(),
+            static_bindings: Default::default(),
             events: Default::default(),
             comments,
-            evaluator: Default::default(),
             binding_collector: VarBindingCollector::new(),
+            renderer: None,
+            shared_templates: vec![],
+            filename: None,
+            source_map: None,
             uid_identifier_map: HashMap::new(),
         }
     }
 
+    /// Attaches the file being transformed, used by `dev` mode to report
+    /// `file:line:column` locations of components and elements.
+    pub fn with_source_map(
+        mut self,
+        filename: Option<String>,
+        source_map: Lrc<SourceMapperDyn>,
+    ) -> Self {
+        self.filename = filename;
+        self.source_map = Some(source_map);
+        self
+    }
+
     pub fn generate_uid_identifier(&mut self, name: &str) -> Ident {
         let name = if name.starts_with('_') {
             name.to_string()
@@ -93,13 +144,34 @@ where
         }
     }
 
-    /// Evaluate an expression using the evaluator.
-    /// Panics if called before visit_mut_module sets up the evaluator.
-    pub fn eval(&mut self, expr: &Expr) -> Option<EvalResult> {
-        self.evaluator
-            .as_mut()
-            .expect("evaluator not initialized")
-            .eval(expr)
+    /// Identifier for a newly registered template. With `stableIds` it is
+    /// derived from the template and the file name rather than a counter, so
+    /// edits elsewhere in the file don't rename it.
+    pub fn generate_template_identifier(&mut self, template: &str) -> Ident {
+        if !self.config.stable_ids {
+            return self.generate_uid_identifier("tmpl$");
+        }
+        let hash = content_hash(&[self.filename.as_deref().unwrap_or_default(), template]);
+        let mut name = format!("_tmpl${hash}");
+        let mut count = 1;
+        while self.templates.iter().any(|t| t.id.sym == name) {
+            count += 1;
+            name = format!("_tmpl${hash}_{count}");
+        }
+        private_ident!(name)
+    }
+
+    /// Runs `f` with fresh identifier counters when `stableIds` is set, so the
+    /// `_el$` style locals of one JSX expression don't depend on the ones
+    /// generated before it.
+    pub fn with_scoped_uids<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        if !self.config.stable_ids {
+            return f(self);
+        }
+        let outer = std::mem::take(&mut self.uid_identifier_map);
+        let result = f(self);
+        self.uid_identifier_map = outer;
+        result
     }
 }
 
@@ -1,5 +1,8 @@
-use super::structs::TemplateInstantiation;
-use crate::shared::utils::{escape_backticks, escape_html, make_iife, trim_whitespace};
+use super::structs::{Renderer, TemplateInstantiation};
+use crate::config::Generate;
+use crate::shared::utils::{
+    ProgramItem, escape_backticks, escape_html, make_iife, trim_whitespace,
+};
 pub use crate::shared::{
     structs::TransformVisitor,
     utils::{get_tag_name, is_component},
@@ -7,10 +10,10 @@ pub use crate::shared::{
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::HashSet;
 use swc_core::{
-    common::{DUMMY_SP, comments::Comments},
+    common::{BytePos, DUMMY_SP, comments::Comments},
     ecma::{
         ast::*,
-        utils::{ExprFactory, private_ident},
+        utils::{ExprFactory, find_pat_ids, private_ident},
         visit::{Visit, VisitMut, VisitMutWith, VisitWith},
     },
 };
@@ -18,6 +21,13 @@ use swc_core::{
 #[derive(Default)]
 pub struct VarBindingCollector {
     pub const_var_bindings: FxHashMap<Id, Option<Expr>>,
+    /// `let` and `var` initializers with the end of their declarator, only
+    /// trusted after it and while the binding is not in `reassigned`.
+    pub let_var_bindings: FxHashMap<Id, (BytePos, Option<Expr>)>,
+    pub reassigned: FxHashSet<Id>,
+    /// Objects that are written to, or used other than by reading a property,
+    /// so their properties may change.
+    pub mutated: FxHashSet<Id>,
     pub function_bindings: FxHashSet<Id>,
 }
 
@@ -40,6 +50,18 @@ impl VarBindingCollector {
             _ => {}
         };
     }
+
+    /// Marks the object at the root of a member chain like `theme.colors.accent`.
+    fn collect_mutated(&mut self, obj: &Expr) {
+        match obj {
+            Expr::Ident(id) => {
+                self.mutated.insert(id.to_id());
+            }
+            Expr::Member(member) => self.collect_mutated(&member.obj),
+            Expr::Paren(paren) => self.collect_mutated(&paren.expr),
+            _ => {}
+        }
+    }
 }
 
 impl Visit for VarBindingCollector {
@@ -62,15 +84,163 @@ impl Visit for VarBindingCollector {
             for decl in &n.decls {
                 self.collect_pat(&decl.name, decl.init.clone().map(|v| *v));
             }
+        } else {
+            for decl in &n.decls {
+                if let Pat::Ident(id) = &decl.name
+                    && self
+                        .let_var_bindings
+                        .insert(id.to_id(), (decl.span.hi, decl.init.clone().map(|v| *v)))
+                        .is_some()
+                {
+                    // a redeclared `var` assigns the binding again
+                    self.reassigned.insert(id.to_id());
+                }
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_head(&mut self, n: &ForHead) {
+        // `for (key of list)` assigns `key` on every iteration
+        if let ForHead::Pat(pat) = n {
+            self.reassigned.extend(find_pat_ids::<_, Id>(pat));
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        match n {
+            // any other use may hand the object to code that changes it
+            Expr::Ident(id) => {
+                self.mutated.insert(id.to_id());
+            }
+            // reading a property leaves the object as it is
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Computed(prop),
+                ..
+            }) if obj.is_ident() => prop.visit_with(self),
+            Expr::Member(MemberExpr { obj, .. }) if obj.is_ident() => {}
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_prop(&mut self, n: &Prop) {
+        if let Prop::Shorthand(id) = n {
+            self.mutated.insert(id.to_id());
+        }
+        n.visit_children_with(self);
+    }
+
+    /// Exported objects can be changed by the modules importing them.
+    fn visit_export_decl(&mut self, n: &ExportDecl) {
+        if let Decl::Var(var) = &n.decl {
+            self.mutated.extend(find_pat_ids::<_, Id>(&var.decls));
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_export_named_specifier(&mut self, n: &ExportNamedSpecifier) {
+        if let ModuleExportName::Ident(id) = &n.orig {
+            self.mutated.insert(id.to_id());
+        }
+    }
+
+    fn visit_callee(&mut self, n: &Callee) {
+        // a method call gets the object as `this`
+        if let Callee::Expr(callee) = n
+            && let Expr::Member(member) = &**callee
+        {
+            self.collect_mutated(&member.obj);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_simple_assign_target(&mut self, n: &SimpleAssignTarget) {
+        match n {
+            SimpleAssignTarget::Ident(id) => {
+                self.reassigned.insert(id.to_id());
+            }
+            SimpleAssignTarget::Member(member) => self.collect_mutated(&member.obj),
+            _ => {}
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_assign_target_pat(&mut self, n: &AssignTargetPat) {
+        self.reassigned.extend(find_pat_ids::<_, Id>(n));
+        n.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        match &*n.arg {
+            Expr::Ident(id) => {
+                self.reassigned.insert(id.to_id());
+            }
+            Expr::Member(member) => self.collect_mutated(&member.obj),
+            _ => {}
         }
         n.visit_children_with(self);
     }
 
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        if n.op == UnaryOp::Delete
+            && let Expr::Member(member) = &*n.arg
+        {
+            self.collect_mutated(&member.obj);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_opening_element(&mut self, n: &JSXOpeningElement) {
+        let native = matches!(&n.name, JSXElementName::Ident(id) if !is_component(&id.sym));
+        for attr in &n.attrs {
+            // `style` and `classList` objects on native elements are only read,
+            // which is what lets them be inlined
+            if native
+                && let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value:
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        })),
+                    ..
+                }) = attr
+                && matches!(&*name.sym, "style" | "classList")
+                && expr.is_ident()
+            {
+                continue;
+            }
+            attr.visit_with(self);
+        }
+    }
+
     fn visit_fn_decl(&mut self, f: &FnDecl) {
         self.function_bindings.insert(f.ident.to_id());
     }
 }
 
+pub struct JsxFinder {
+    found: bool,
+}
+
+impl Visit for JsxFinder {
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+}
+
+/// Whether `node` contains any JSX, letting files without it pass through untouched.
+pub fn contains_jsx<N: VisitWith<JsxFinder>>(node: &N) -> bool {
+    let mut finder = JsxFinder { found: false };
+    node.visit_with(&mut finder);
+    finder.found
+}
+
 pub struct ThisBlockVisitor {
     this_id: Option<Ident>,
     has_jsx: bool,
@@ -141,6 +311,7 @@ pub struct TransformInfo<'a> {
     pub fragment_child: bool,
     pub to_be_closed: Option<&'a HashSet<String>>,
     pub do_not_escape: bool,
+    pub preserve_whitespace: bool,
 }
 
 impl<C> TransformVisitor<C>
@@ -156,8 +327,10 @@ where
                 ..Default::default()
             },
         };
-        let result = self.transform_node(node, &info);
-        self.create_template(result.unwrap(), false)
+        self.with_scoped_uids(|visitor| {
+            let result = visitor.transform_node(node, &info);
+            visitor.create_template(result.unwrap(), false)
+        })
     }
 
     pub fn transform_node(
@@ -172,13 +345,21 @@ where
             self.transform_fragment_children(node.children, &mut results);
             return Some(results);
         } else if let JSXElementChild::JSXText(node) = node {
-            let text =
-                trim_whitespace(&html_escape::encode_text(&node.value).replace('\u{a0}', "&nbsp;"));
+            let text = if info.do_not_escape {
+                node.value.to_string()
+            } else {
+                html_escape::encode_text(&node.value).replace('\u{a0}', "&nbsp;")
+            };
+            let text = if info.preserve_whitespace {
+                text.replace('\r', "")
+            } else {
+                trim_whitespace(&text)
+            };
             if text.is_empty() {
                 return None;
             }
             let mut results = TemplateInstantiation {
-                template: escape_backticks(&text),
+                template: self.escape_template_text(&text),
                 text: true,
                 ..TemplateInstantiation::default()
             };
@@ -196,7 +377,7 @@ where
                 return None;
             }
             let mut results = TemplateInstantiation {
-                template: escape_backticks(&text),
+                template: self.escape_template_text(&text),
                 text: true,
                 ..TemplateInstantiation::default()
             };
@@ -220,12 +401,13 @@ where
                     ) {
                         return Some(TemplateInstantiation {
                             exprs: vec![*exp],
+                            span,
                             ..Default::default()
                         });
                     }
                     let mut expr = vec![];
                     if self.config.wrap_conditionals
-                        && self.config.generate != "ssr"
+                        && self.config.generate != Generate::Ssr
                         && matches!(*exp, Expr::Bin(_) | Expr::Cond(_))
                     {
                         let result = self.transform_condition(*exp, info.component_child, false);
@@ -238,7 +420,7 @@ where
                     } else {
                         let mut flag = false;
                         if !info.component_child
-                            && (self.config.generate != "ssr" || info.fragment_child)
+                            && (self.config.generate != Generate::Ssr || info.fragment_child)
                             && let Expr::Call(CallExpr {
                                 callee: Callee::Expr(ref ex),
                                 ref args,
@@ -257,6 +439,7 @@ where
                     return Some(TemplateInstantiation {
                         exprs: expr,
                         dynamic: true,
+                        span,
                         ..Default::default()
                     });
                 }
@@ -284,6 +467,16 @@ where
         None
     }
 
+    /// SSR templates are emitted as string literals rather than template
+    /// literals, so backticks only need escaping for the DOM output.
+    fn escape_template_text(&self, text: &str) -> String {
+        if self.config.generate == Generate::Ssr {
+            text.to_string()
+        } else {
+            escape_backticks(text)
+        }
+    }
+
     pub fn transform_jsx_expr(&mut self, node: JSXElement) -> Expr {
         let results = self.transform_element(
             node,
@@ -304,10 +497,108 @@ where
         node: JSXElement,
         info: &TransformInfo,
     ) -> TemplateInstantiation {
+        let span = node.span;
         let tag_name = get_tag_name(&node);
-        if is_component(&tag_name) {
-            return self.transform_component(node);
+        let mut results = if is_component(&tag_name) {
+            self.transform_component(node)
+        } else {
+            match self.config.generate {
+                Generate::Ssr => self.transform_element_ssr(node, info),
+                Generate::Universal => self.transform_element_universal(node),
+                Generate::Dynamic => self.transform_element_dynamic(node, &tag_name, info),
+                Generate::Dom => self.transform_element_dom(node, info),
+            }
+        };
+        results.span = span;
+        results
+    }
+
+    /// The renderer of `Config.renderers` listing `tag_name`. Other tags go
+    /// to the universal backend, through the renderer named `universal` when
+    /// there is one and `moduleName` otherwise.
+    fn find_renderer(&self, tag_name: &str) -> Renderer {
+        let renderers = &self.config.renderers;
+        match renderers
+            .iter()
+            .find(|r| r.elements.iter().any(|e| e == tag_name))
+            .or_else(|| renderers.iter().find(|r| r.name == "universal"))
+        {
+            Some(renderer) => Renderer {
+                dom: renderer.name == "dom",
+                module_name: renderer.module_name.clone(),
+            },
+            None => Renderer {
+                dom: false,
+                module_name: self.config.module_name.clone(),
+            },
+        }
+    }
+
+    /// Routes an element to the DOM or universal backend depending on which
+    /// of `Config.renderers` lists its tag.
+    fn transform_element_dynamic(
+        &mut self,
+        node: JSXElement,
+        tag_name: &str,
+        info: &TransformInfo,
+    ) -> TemplateInstantiation {
+        let renderer = self.find_renderer(tag_name);
+        // an element can't join the template of another renderer, so it is
+        // compiled on its own and inserted into its parent like a component
+        if !info.top_level
+            && let Some(parent_renderer) = self.renderer.take_if(|parent| *parent != renderer)
+        {
+            let results = self.transform_element_dynamic(
+                node,
+                tag_name,
+                &TransformInfo {
+                    top_level: true,
+                    last_element: true,
+                    ..Default::default()
+                },
+            );
+            let expr = self.create_template(results, false);
+            self.renderer = Some(parent_renderer);
+            return TemplateInstantiation {
+                exprs: vec![expr],
+                component: true,
+                ..Default::default()
+            };
+        }
+        let parent_renderer = self.renderer.replace(renderer.clone());
+        let mut results = if renderer.dom {
+            self.transform_element_dom(node, info)
+        } else {
+            self.transform_element_universal(node)
+        };
+        self.renderer = parent_renderer;
+        results.renderer = Some(renderer);
+        results
+    }
+
+    pub fn create_template(&mut self, result: TemplateInstantiation, wrap: bool) -> Expr {
+        match self.config.generate {
+            Generate::Ssr => self.create_template_ssr(result),
+            Generate::Universal => self.create_template_universal(result, wrap),
+            Generate::Dynamic if let Some(renderer) = result.renderer.clone() => {
+                let dom = renderer.dom;
+                let parent_renderer = self.renderer.replace(renderer);
+                let expr = if dom {
+                    self.create_template_dom(result, wrap)
+                } else {
+                    self.create_template_universal(result, wrap)
+                };
+                self.renderer = parent_renderer;
+                expr
+            }
+            _ => self.create_template_dom(result, wrap),
+        }
+    }
+
+    pub fn append_templates<T: ProgramItem>(&mut self, body: &mut Vec<T>) {
+        if self.config.generate == Generate::Ssr {
+            return self.append_templates_ssr(body);
         }
-        self.transform_element_dom(node, info)
+        self.append_templates_dom(body)
     }
 }
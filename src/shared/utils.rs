@@ -1,8 +1,9 @@
-use crate::TransformVisitor;
+use super::evaluate::EvalResult;
+use crate::{TransformVisitor, config::Generate};
 use convert_case::{Case, Converter};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 /// Helper trait to extract the first element from a collection, consuming it.
 pub trait IntoFirst<T> {
@@ -19,11 +20,18 @@ impl<T> IntoFirst<T> for Vec<T> {
 
 use swc_core::{
     atoms::Atom,
-    common::{BytePos, DUMMY_SP, Span, comments::Comments, iter::IdentifyLast},
+    common::{
+        BytePos, DUMMY_SP, Span, Spanned,
+        comments::{Comment, Comments},
+        errors::HANDLER,
+        iter::IdentifyLast,
+    },
     ecma::{
         ast::*,
-        minifier::eval::EvalResult,
-        utils::{ExprFactory, prepend_stmt, private_ident, quote_ident},
+        utils::{
+            ExprFactory, StmtLike, is_maybe_branch_directive, prepend_stmt, private_ident,
+            quote_ident,
+        },
         visit::{Visit, VisitWith},
     },
     quote,
@@ -79,37 +87,270 @@ impl<C> TransformVisitor<C>
 where
     C: Comments,
 {
+    /// Module that helpers for the element being transformed are imported
+    /// from. In dynamic mode this follows the renderer owning the element,
+    /// and the `dom` renderer outside of elements.
+    pub fn renderer_module_name(&self) -> String {
+        if let Some(renderer) = &self.renderer {
+            return renderer.module_name.clone();
+        }
+        if self.config.generate == Generate::Dynamic
+            && let Some(renderer) = self.config.renderers.iter().find(|r| r.name == "dom")
+        {
+            return renderer.module_name.clone();
+        }
+        self.config.module_name.clone()
+    }
+
+    /// Comments leading the program, where file level pragmas live.
+    fn get_leading_comments<T: Spanned>(&self, span: Span, body: &[T]) -> Vec<Comment> {
+        let positions = [Some(span.lo), body.first().map(|item| item.span().lo)];
+        positions
+            .into_iter()
+            .flatten()
+            .filter_map(|pos| self.comments.get_leading(pos))
+            .flatten()
+            .collect()
+    }
+
+    /// Reads the `@jsxImportSource` pragma from the comments leading the program.
+    pub fn get_jsx_import_source<T: Spanned>(&self, span: Span, body: &[T]) -> Option<String> {
+        self.get_leading_comments(span, body)
+            .into_iter()
+            .find_map(|comment| {
+                let mut words = comment.text.split_whitespace();
+                words.find(|word| *word == "@jsxImportSource")?;
+                words.next().map(|source| source.to_string())
+            })
+    }
+
+    /// `file:line:column` of `span` in the original source, used by `dev` mode.
+    pub fn source_location(&self, span: Span) -> Option<String> {
+        let source_map = self.source_map.as_ref()?;
+        if span.is_dummy() {
+            return None;
+        }
+        let loc = source_map.lookup_char_pos(span.lo);
+        let filename = match &self.filename {
+            Some(filename) => filename.clone(),
+            None => loc.file.name.to_string(),
+        };
+        Some(format!("{}:{}:{}", filename, loc.line, loc.col.0 + 1))
+    }
+
+    /// Merges a leading `@dom-expressions` pragma over the config, e.g.
+    /// `/** @dom-expressions generate=ssr hydratable */`. Only `generate`,
+    /// `hydratable`, `delegateEvents`, `moduleName` and `nonDelegatedEvents`
+    /// can be overridden, the last as a comma separated list such as
+    /// `nonDelegatedEvents=input,keydown` that extends the configured one.
+    pub fn apply_config_pragma<T: Spanned>(&mut self, span: Span, body: &[T]) {
+        for comment in self.get_leading_comments(span, body) {
+            let mut words = comment.text.split_whitespace();
+            if words.find(|word| *word == "@dom-expressions").is_none() {
+                continue;
+            }
+            for word in words.take_while(|word| !word.starts_with('@') && *word != "*/") {
+                let word = word.trim_matches('*');
+                if word.is_empty() {
+                    continue;
+                }
+                let (key, value) = word.split_once('=').unwrap_or((word, "true"));
+                let flag = match value {
+                    "true" => Some(true),
+                    "false" => Some(false),
+                    _ => None,
+                };
+                match (key, flag) {
+                    ("generate", _) => {
+                        match serde_json::from_value(serde_json::Value::String(value.into())) {
+                            Ok(generate) => self.config.generate = generate,
+                            Err(err) => emit_error(
+                                comment.span,
+                                &format!("Invalid @dom-expressions pragma: {err}"),
+                            ),
+                        }
+                    }
+                    ("moduleName", _) => self.config.module_name = value.to_string(),
+                    ("nonDelegatedEvents", _) => self.config.non_delegated_events.extend(
+                        value
+                            .split(',')
+                            .filter(|event| !event.is_empty())
+                            .map(|event| event.to_string()),
+                    ),
+                    ("hydratable", Some(flag)) => self.config.hydratable = flag,
+                    ("delegateEvents", Some(flag)) => self.config.delegate_events = flag,
+                    ("hydratable" | "delegateEvents", None) => emit_error(
+                        comment.span,
+                        &format!("Invalid @dom-expressions pragma: `{key}` expects true or false"),
+                    ),
+                    _ => emit_error(
+                        comment.span,
+                        &format!(
+                            "Invalid @dom-expressions pragma: unknown option `{key}`, expected one of `generate`, `hydratable`, `delegateEvents`, `nonDelegatedEvents`, `moduleName`"
+                        ),
+                    ),
+                }
+            }
+        }
+    }
+
     pub fn register_import_method(&mut self, name: &str) -> Ident {
+        if let Some(helper) = self.config.helper_imports.get(name) {
+            let module_name = helper.module_name().to_string();
+            let import_name = helper.import_name(name).to_string();
+            return self.register_import_from(module_name, &import_name);
+        }
+        self.register_import_from(self.renderer_module_name(), name)
+    }
+
+    /// Like `register_import_method`, for helpers of a module other than the renderer.
+    pub fn register_import_from(&mut self, module_name: String, name: &str) -> Ident {
+        let key = (module_name, name.to_string());
+        if let Some(local) = self.existing_imports.get(&key) {
+            return local.clone();
+        }
         self.imports
-            .entry(name.to_string())
+            .entry(key)
             .or_insert_with(|| private_ident!(format!("_${}", name)))
             .clone()
     }
 
+    /// Records the value imports already present in the module so helpers
+    /// the user imported themselves are reused rather than imported again.
+    pub fn collect_existing_imports(&mut self, module: &Module) {
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if import.type_only || import.phase != ImportPhase::Evaluation {
+                continue;
+            }
+            let source = import.src.value.to_string_lossy().to_string();
+            for specifier in &import.specifiers {
+                let ImportSpecifier::Named(specifier) = specifier else {
+                    continue;
+                };
+                if specifier.is_type_only {
+                    continue;
+                }
+                let name = match &specifier.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                    Some(ModuleExportName::Str(name)) => name.value.to_string_lossy().to_string(),
+                    None => specifier.local.sym.to_string(),
+                };
+                self.existing_imports
+                    .entry((source.clone(), name))
+                    .or_insert_with(|| specifier.local.clone());
+            }
+        }
+    }
+
+    /// Whether text inside `tag_name` keeps its whitespace, per `whitespaceSensitiveTags`.
+    pub fn preserves_whitespace(&self, tag_name: &str) -> bool {
+        self.config
+            .whitespace_sensitive_tags
+            .iter()
+            .any(|tag| tag == tag_name)
+    }
+
+    /// Records the bindings imported from `staticModules`, whose values
+    /// `is_dynamic` treats as never changing.
+    pub fn collect_static_bindings(&mut self, module: &Module) {
+        if self.config.static_modules.is_empty() {
+            return;
+        }
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if !self
+                .config
+                .is_static_module(&import.src.value.to_string_lossy())
+            {
+                continue;
+            }
+            for specifier in &import.specifiers {
+                self.static_bindings.insert(specifier.local().to_id());
+            }
+        }
+    }
+
+    /// Whether `callee` is one of the `staticFunctions`, by dotted name such
+    /// as `t` or `i18n.t`, or reads from a `staticModules` import.
+    fn is_static_callee(&self, callee: &Callee) -> bool {
+        let Callee::Expr(callee) = callee else {
+            return false;
+        };
+        member_path(callee).is_some_and(|path| self.config.static_functions.contains(&path))
+            || self.is_static_member(callee)
+    }
+
+    /// Whether `expr` is an identifier imported from `staticModules`, or a
+    /// member expression rooted in one.
+    fn is_static_member(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(id) => self.static_bindings.contains(&id.to_id()),
+            Expr::Member(MemberExpr { obj, .. }) => self.is_static_member(obj),
+            _ => false,
+        }
+    }
+
+    /// Drains the registered helpers, grouped by module and sorted by name.
+    fn take_imports_by_source(&mut self) -> BTreeMap<String, Vec<(String, Ident)>> {
+        let mut sources: BTreeMap<String, Vec<(String, Ident)>> = BTreeMap::new();
+        for ((source, name), local) in std::mem::take(&mut self.imports) {
+            sources.entry(source).or_default().push((name, local));
+        }
+        for specifiers in sources.values_mut() {
+            specifiers.sort_by(|(a, _), (b, _)| a.cmp(b));
+        }
+        sources
+    }
+
     pub fn insert_imports(&mut self, module: &mut Module) {
-        let mut entries = std::mem::take(&mut self.imports)
-            .into_iter()
-            .collect::<Vec<_>>();
-        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
-        for (name, val) in entries {
+        for (source, specifiers) in self.take_imports_by_source().into_iter().rev() {
+            let names: Vec<String> = specifiers.iter().map(|(name, _)| name.clone()).collect();
+            let specifiers = specifiers.into_iter().map(|(name, local)| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    local,
+                    imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                        name.into(),
+                        DUMMY_SP,
+                    ))),
+                    span: DUMMY_SP,
+                    is_type_only: false,
+                })
+            });
+            // merge into a named import of the same module when there is one
+            let existing = module.body.iter_mut().find_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import))
+                    if !import.type_only
+                        && import.phase == ImportPhase::Evaluation
+                        && import.src.value.to_string_lossy() == source
+                        && !import
+                            .specifiers
+                            .iter()
+                            .any(|s| matches!(s, ImportSpecifier::Namespace(_))) =>
+                {
+                    Some(import)
+                }
+                _ => None,
+            });
+            if let Some(import) = existing {
+                import.specifiers.extend(specifiers);
+                import.span = self.annotate_shared_templates(&source, &names, import.span);
+                continue;
+            }
             prepend_stmt(
                 &mut module.body,
                 ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                    specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
-                        local: val,
-                        imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
-                            name.into(),
-                            DUMMY_SP,
-                        ))),
-                        span: DUMMY_SP,
-                        is_type_only: false,
-                    })],
+                    specifiers: specifiers.collect(),
                     src: Box::new(Str {
                         span: DUMMY_SP,
-                        value: self.config.module_name.clone().into(),
+                        value: source.as_str().into(),
                         raw: None,
                     }),
-                    span: DUMMY_SP,
+                    span: self.annotate_shared_templates(&source, &names, DUMMY_SP),
                     type_only: false,
                     with: None,
                     phase: ImportPhase::default(),
@@ -118,12 +359,43 @@ where
         }
     }
 
-    pub fn insert_events(&mut self, module: &mut Module) {
+    /// Script counterpart of `insert_imports`: helpers are destructured from a
+    /// single `require()` call per module.
+    pub fn insert_requires(&mut self, body: &mut Vec<Stmt>) {
+        for (source, specifiers) in self.take_imports_by_source().into_iter().rev() {
+            let names: Vec<String> = specifiers.iter().map(|(name, _)| name.clone()).collect();
+            let pattern = Pat::Object(ObjectPat {
+                span: DUMMY_SP,
+                props: specifiers
+                    .into_iter()
+                    .map(|(name, local)| {
+                        ObjectPatProp::KeyValue(KeyValuePatProp {
+                            key: PropName::Ident(IdentName::new(name.into(), DUMMY_SP)),
+                            value: Box::new(Pat::Ident(local.into())),
+                        })
+                    })
+                    .collect(),
+                optional: false,
+                type_ann: None,
+            });
+            let mut stmt = quote!(
+                "const $pattern = require($source);" as Stmt,
+                pattern: Pat = pattern,
+                source: Expr = source.as_str().into()
+            );
+            if let Stmt::Decl(Decl::Var(var)) = &mut stmt {
+                var.span = self.annotate_shared_templates(&source, &names, var.span);
+            }
+            prepend_stmt(body, stmt);
+        }
+    }
+
+    pub fn insert_events<T: StmtLike>(&mut self, body: &mut Vec<T>) {
         if !self.events.is_empty() {
             let mut elems: Vec<_> = std::mem::take(&mut self.events).into_iter().collect();
             elems.sort();
             let delegate_events = self.register_import_method("delegateEvents");
-            module.body.push(
+            body.push(
                 quote!(
                     "$delegate_events($elems)" as Stmt,
                     delegate_events = delegate_events,
@@ -141,6 +413,7 @@ where
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     pub fn transform_condition(
         &mut self,
         mut node: Expr,
@@ -346,15 +619,21 @@ where
         &self,
         expr: &Expr,
         span: Option<Span>,
-        check_member: bool,
+        mut check_member: bool,
         check_tags: bool,
-        check_call_expression: bool,
-        _native: bool,
+        mut check_call_expression: bool,
+        native: bool,
     ) -> bool {
         if matches!(expr, Expr::Fn(_) | Expr::Arrow(_)) {
             return false;
         }
 
+        // Server output is rendered once, so reads of native children never need tracking
+        if self.config.generate == Generate::Ssr && native {
+            check_member = false;
+            check_call_expression = false;
+        }
+
         if let Some(span) = span {
             let pos = span.lo + BytePos(1);
             if let Some(mut cmts) = self.comments.take_trailing(pos)
@@ -367,8 +646,8 @@ where
         }
 
         if match expr {
-            Expr::Call(_) => check_call_expression,
-            Expr::Member(_) => check_member,
+            Expr::Call(call) => check_call_expression && !self.is_static_callee(&call.callee),
+            Expr::Member(member) => check_member && !self.is_static_member(&member.obj),
             Expr::OptChain(_) => check_member,
             Expr::Bin(BinExpr {
                 op: BinaryOp::In, ..
@@ -381,7 +660,7 @@ where
         }
 
         let mut dyn_visitor = DynamicVisitor {
-            _transform_visitor: self,
+            transform_visitor: self,
             check_member,
             check_tags,
             check_call_expression,
@@ -398,7 +677,7 @@ struct DynamicVisitor<'a, C>
 where
     C: Comments,
 {
-    _transform_visitor: &'a TransformVisitor<C>,
+    transform_visitor: &'a TransformVisitor<C>,
     check_member: bool,
     check_tags: bool,
     check_call_expression: bool,
@@ -420,7 +699,10 @@ where
         if self.is_stop {
             return;
         }
-        if self.check_call_expression {
+        if self.transform_visitor.is_static_callee(&c.callee) {
+            // only the arguments of a static function can make the call reactive
+            c.args.visit_with(self);
+        } else if self.check_call_expression {
             self.dynamic = true;
             self.is_stop = true;
         } else {
@@ -442,7 +724,7 @@ where
         if self.is_stop {
             return;
         }
-        if self.check_member {
+        if self.check_member && !self.transform_visitor.is_static_member(&e.obj) {
             self.dynamic = true;
             self.is_stop = true;
         } else {
@@ -502,6 +784,20 @@ where
     }
 }
 
+/// The dotted name of an identifier or a chain of non-computed member
+/// accesses, like `i18n.t`.
+fn member_path(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(id) => Some(id.sym.to_string()),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => Some(format!("{}.{}", member_path(obj)?, prop.sym)),
+        _ => None,
+    }
+}
+
 pub fn filter_children(c: &JSXElementChild) -> bool {
     match c {
         JSXElementChild::JSXText(t) => !NEWLINE_WHITESPACE_REGEX.is_match(&t.raw),
@@ -513,6 +809,18 @@ pub fn filter_children(c: &JSXElementChild) -> bool {
     }
 }
 
+/// `filter_children` for an element keeping its whitespace, where text made
+/// only of whitespace and newlines is content too.
+pub fn filter_children_preserving(preserve_whitespace: bool) -> impl Fn(&JSXElementChild) -> bool {
+    move |c| filter_children(c) || preserve_whitespace && matches!(c, JSXElementChild::JSXText(_))
+}
+
+/// The HTML parser drops a newline right after the opening tag of these
+/// elements, so content starting with one needs a second.
+pub fn drops_leading_newline(tag_name: &str) -> bool {
+    matches!(tag_name, "pre" | "textarea" | "listing")
+}
+
 pub fn convert_jsx_identifier(attr_name: &JSXAttrName) -> (PropName, String) {
     let name = match &attr_name {
         JSXAttrName::Ident(ident) => ident.sym.to_string(),
@@ -536,7 +844,10 @@ pub fn convert_jsx_identifier(attr_name: &JSXAttrName) -> (PropName, String) {
     }
 }
 
-pub fn check_length(children: &[JSXElementChild]) -> bool {
+/// Whether more than one child takes part in the output. Whitespace-only text
+/// counts when `preserve_whitespace` keeps it in the template, so inserts
+/// next to it are anchored instead of replacing it.
+pub fn check_length(children: &[JSXElementChild], preserve_whitespace: bool) -> bool {
     let mut i = 0;
     for child in children {
         if !matches!(
@@ -547,7 +858,10 @@ pub fn check_length(children: &[JSXElementChild]) -> bool {
             })
         ) {
             if let JSXElementChild::JSXText(t) = child {
-                if !ALL_WHITESPACE_REGEX.is_match(&t.raw) || ALL_SPACES_REGEX.is_match(&t.raw) {
+                if preserve_whitespace
+                    || !ALL_WHITESPACE_REGEX.is_match(&t.raw)
+                    || ALL_SPACES_REGEX.is_match(&t.raw)
+                {
                     i += 1;
                 }
             } else {
@@ -683,18 +997,136 @@ pub fn is_static_expr(expr: &Expr) -> bool {
     }
 }
 
+/// Statement containers the generated declarations can be hoisted into.
+pub trait ProgramItem: StmtLike {
+    fn is_import(&self) -> bool;
+}
+
+impl ProgramItem for Stmt {
+    fn is_import(&self) -> bool {
+        false
+    }
+}
+
+impl ProgramItem for ModuleItem {
+    fn is_import(&self) -> bool {
+        matches!(self, ModuleItem::ModuleDecl(ModuleDecl::Import(_)))
+    }
+}
+
+/// Inserts `stmt` after the directives and import declarations leading `body`.
+pub fn insert_after_imports<T: ProgramItem>(body: &mut Vec<T>, stmt: T) {
+    let index = body
+        .iter()
+        .position(|item| {
+            !item.is_import() && !item.as_stmt().is_some_and(is_maybe_branch_directive)
+        })
+        .unwrap_or(body.len());
+    body.insert(index, stmt);
+}
+
+/// Returns the expression body of an arrow produced by `transform_condition`,
+/// or the expression itself when it isn't one.
+pub fn unwrap_arrow_body(expr: Expr) -> Expr {
+    match expr {
+        Expr::Arrow(ArrowExpr { body, .. }) if body.is_expr() => *body.expect_expr(),
+        expr => expr,
+    }
+}
+
+/// Points a generated expression at the JSX it was compiled from, so source
+/// maps and stack traces resolve to the original line.
+pub fn with_span(mut expr: Expr, span: Span) -> Expr {
+    match &mut expr {
+        Expr::Call(call) => call.span = span,
+        Expr::Assign(assign) => assign.span = span,
+        Expr::Arrow(arrow) => arrow.span = span,
+        Expr::Bin(bin) => bin.span = span,
+        Expr::Cond(cond) => cond.span = span,
+        Expr::Member(member) => member.span = span,
+        _ => {}
+    }
+    expr
+}
+
+fn fnv1a(parts: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in parts.join("\0").bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Short FNV-1a digest of `parts`, stable across builds and platforms.
+pub fn content_hash(parts: &[&str]) -> String {
+    let hash = fnv1a(parts);
+    format!("{:08x}", (hash ^ (hash >> 32)) as u32)
+}
+
+/// Full 64-bit `content_hash`, for names shared by every module of an app.
+pub fn long_content_hash(parts: &[&str]) -> String {
+    format!("{:016x}", fnv1a(parts))
+}
+
+/// Reports a transform error at `span` through the SWC diagnostics handler.
+pub fn emit_error(span: Span, message: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+}
+
 pub fn lit_to_string(lit: &Lit) -> String {
     match lit {
-        Lit::Str(value) => value.value.as_atom().unwrap().to_string(),
+        Lit::Str(value) => value.value.to_string_lossy().to_string(),
         Lit::Bool(value) => value.value.to_string(),
         Lit::Null(_) => "null".to_string(),
-        Lit::Num(value) => value.value.to_string(),
+        Lit::Num(value) => number_to_string(value.value),
         Lit::BigInt(value) => value.value.to_string(),
         Lit::Regex(value) => value.exp.to_string(),
         Lit::JSXText(value) => value.value.to_string(),
     }
 }
 
+/// Formats a number the way JavaScript's `String(value)` does, which differs
+/// from Rust for non-finite values, `-0` and very large or small magnitudes.
+pub fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    // shortest round-trip digits, as `d.ddde±x`
+    let exponential = format!("{:e}", value.abs());
+    let (mantissa, exponent) = exponential.split_once('e').unwrap();
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap() + 1;
+    let body = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        let fraction = if k > 1 {
+            format!(".{}", &digits[1..])
+        } else {
+            String::new()
+        };
+        let exponent_sign = if n > 0 { "+" } else { "-" };
+        format!(
+            "{}{fraction}e{exponent_sign}{}",
+            &digits[..1],
+            (n - 1).abs()
+        )
+    };
+    format!("{sign}{body}")
+}
+
 pub fn is_l_val(expr: &Expr) -> bool {
     matches!(
         expr,
@@ -0,0 +1,289 @@
+use crate::TransformVisitor;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use swc_core::{common::comments::Comments, ecma::ast::*};
+
+use super::utils::{emit_error, get_tag_name, is_component};
+
+static HEADINGS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+
+// Elements whose content model only allows the listed children
+static ONLY_VALID_CHILDREN: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
+    HashMap::from([
+        (
+            "head",
+            HashSet::from([
+                "base", "basefont", "bgsound", "link", "meta", "title", "noscript", "noframes",
+                "style", "script", "template",
+            ]),
+        ),
+        ("optgroup", HashSet::from(["option"])),
+        ("select", HashSet::from(["optgroup", "option", "hr"])),
+        (
+            "table",
+            HashSet::from(["caption", "colgroup", "tbody", "tfoot", "thead"]),
+        ),
+        ("tr", HashSet::from(["td", "th"])),
+        ("colgroup", HashSet::from(["col"])),
+        ("tbody", HashSet::from(["tr"])),
+        ("thead", HashSet::from(["tr"])),
+        ("tfoot", HashSet::from(["tr"])),
+        ("script", HashSet::new()),
+        ("iframe", HashSet::new()),
+        ("option", HashSet::new()),
+        ("textarea", HashSet::new()),
+        ("style", HashSet::new()),
+        ("title", HashSet::new()),
+    ])
+});
+
+// Elements that can only be placed inside the listed parents
+static ONLY_VALID_PARENTS: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
+    HashMap::from([
+        ("html", HashSet::new()),
+        ("body", HashSet::from(["html"])),
+        ("head", HashSet::from(["html"])),
+        ("td", HashSet::from(["tr"])),
+        ("colgroup", HashSet::from(["table"])),
+        ("caption", HashSet::from(["table"])),
+        ("tbody", HashSet::from(["table"])),
+        ("tfoot", HashSet::from(["table"])),
+        ("col", HashSet::from(["colgroup"])),
+        ("th", HashSet::from(["tr"])),
+        ("thead", HashSet::from(["table"])),
+        ("tr", HashSet::from(["tbody", "thead", "tfoot"])),
+        ("dd", HashSet::from(["dl", "div"])),
+        ("dt", HashSet::from(["dl", "div"])),
+        ("figcaption", HashSet::from(["figure"])),
+        ("summary", HashSet::from(["details"])),
+        ("area", HashSet::from(["map"])),
+    ])
+});
+
+// Children that implicitly close the parent or are dropped by the parser
+static KNOWN_INVALID_CHILDREN: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
+    HashMap::from([
+        (
+            "p",
+            HashSet::from([
+                "address",
+                "article",
+                "aside",
+                "blockquote",
+                "center",
+                "details",
+                "dialog",
+                "dir",
+                "div",
+                "dl",
+                "fieldset",
+                "figure",
+                "footer",
+                "form",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "header",
+                "hgroup",
+                "hr",
+                "li",
+                "main",
+                "nav",
+                "menu",
+                "ol",
+                "p",
+                "pre",
+                "section",
+                "table",
+                "ul",
+            ]),
+        ),
+        (
+            "svg",
+            HashSet::from([
+                "b",
+                "blockquote",
+                "br",
+                "code",
+                "dd",
+                "div",
+                "dl",
+                "dt",
+                "em",
+                "embed",
+                "h1",
+                "h2",
+                "h3",
+                "h4",
+                "h5",
+                "h6",
+                "hr",
+                "i",
+                "img",
+                "li",
+                "menu",
+                "meta",
+                "ol",
+                "p",
+                "pre",
+                "ruby",
+                "s",
+                "small",
+                "span",
+                "strong",
+                "sub",
+                "sup",
+                "table",
+                "u",
+                "ul",
+                "var",
+            ]),
+        ),
+    ])
+});
+
+// Parents that the listed children can not be nested in
+static KNOWN_INVALID_PARENTS: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
+    HashMap::from([
+        ("a", HashSet::from(["a"])),
+        ("button", HashSet::from(["button"])),
+        ("dd", HashSet::from(["dd", "dt"])),
+        ("dt", HashSet::from(["dd", "dt"])),
+        ("form", HashSet::from(["form"])),
+        ("li", HashSet::from(["li"])),
+        ("h1", HashSet::from(HEADINGS)),
+        ("h2", HashSet::from(HEADINGS)),
+        ("h3", HashSet::from(HEADINGS)),
+        ("h4", HashSet::from(HEADINGS)),
+        ("h5", HashSet::from(HEADINGS)),
+        ("h6", HashSet::from(HEADINGS)),
+    ])
+});
+
+// Elements that stop the parser from closing or breaking out of an open
+// ancestor, so descendants below them are not checked against it
+static DEFAULT_SCOPE: [&str; 12] = [
+    "applet",
+    "caption",
+    "html",
+    "table",
+    "td",
+    "th",
+    "marquee",
+    "object",
+    "template",
+    "foreignObject",
+    "desc",
+    "title",
+];
+
+fn is_scope_boundary(ancestor: &str, tag: &str) -> bool {
+    DEFAULT_SCOPE.contains(&tag)
+        || match ancestor {
+            "p" => tag == "button",
+            "li" => matches!(tag, "ol" | "ul"),
+            "dd" | "dt" => tag == "dl",
+            _ => false,
+        }
+}
+
+/// Returns false when the browser would close or break out of `ancestor` on
+/// reaching `descendant` nested below one of its children.
+pub fn is_valid_html_descendant(ancestor: &str, descendant: &str) -> bool {
+    if KNOWN_INVALID_CHILDREN
+        .get(ancestor)
+        .is_some_and(|children| children.contains(descendant))
+    {
+        return false;
+    }
+    // headings are only closed by a heading that is their direct child
+    HEADINGS.contains(&ancestor)
+        || !KNOWN_INVALID_PARENTS
+            .get(descendant)
+            .is_some_and(|parents| parents.contains(ancestor))
+}
+
+/// Returns false when the browser would not keep `child` as a direct child of
+/// `parent` while parsing the template.
+pub fn is_valid_html_nesting(parent: &str, child: &str) -> bool {
+    if let Some(children) = ONLY_VALID_CHILDREN.get(parent) {
+        return children.contains(child);
+    }
+    if let Some(parents) = ONLY_VALID_PARENTS.get(child) {
+        return parents.contains(parent);
+    }
+    if KNOWN_INVALID_CHILDREN
+        .get(parent)
+        .is_some_and(|children| children.contains(child))
+    {
+        return false;
+    }
+    if KNOWN_INVALID_PARENTS
+        .get(child)
+        .is_some_and(|parents| parents.contains(parent))
+    {
+        return false;
+    }
+    true
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    /// Reports native children the HTML parser would move out of `tag_name`,
+    /// as the element walks generated for the template would no longer match.
+    pub fn validate_children(&self, tag_name: &str, children: &[JSXElementChild]) {
+        if !self.config.validate {
+            return;
+        }
+        for child in children {
+            let JSXElementChild::JSXElement(child) = child else {
+                continue;
+            };
+            let child_tag = get_tag_name(child);
+            if is_component(&child_tag) || is_valid_html_nesting(tag_name, &child_tag) {
+                continue;
+            }
+            emit_error(
+                child.opening.span,
+                &format!("Invalid JSX: <{child_tag}> cannot be child of <{tag_name}>"),
+            );
+        }
+        for child in children {
+            if let JSXElementChild::JSXElement(child) = child
+                && let child_tag = get_tag_name(child)
+                && !is_component(&child_tag)
+                && !is_scope_boundary(tag_name, &child_tag)
+            {
+                self.validate_descendants(tag_name, &child.children);
+            }
+        }
+    }
+
+    /// Reports elements deeper in the template that the parser would hoist out
+    /// of the open `ancestor`, stopping at components and scoping elements.
+    fn validate_descendants(&self, ancestor: &str, children: &[JSXElementChild]) {
+        for child in children {
+            let JSXElementChild::JSXElement(child) = child else {
+                continue;
+            };
+            let child_tag = get_tag_name(child);
+            if is_component(&child_tag) {
+                continue;
+            }
+            if !is_valid_html_descendant(ancestor, &child_tag) {
+                emit_error(
+                    child.opening.span,
+                    &format!("Invalid JSX: <{child_tag}> cannot be nested inside <{ancestor}>"),
+                );
+            }
+            if !is_scope_boundary(ancestor, &child_tag) {
+                self.validate_descendants(ancestor, &child.children);
+            }
+        }
+    }
+}
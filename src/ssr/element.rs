@@ -0,0 +1,743 @@
+use super::template::{append_ssr_template, push_ssr_value};
+use crate::{
+    TransformVisitor,
+    shared::{
+        constants::{ALIASES, BOOLEANS, CHILD_PROPERTIES, VOID_ELEMENTS},
+        structs::TemplateInstantiation,
+        transform::{TransformInfo, is_component},
+        utils::{
+            IntoFirst, RESERVED_NAME_SPACES, convert_jsx_identifier, drops_leading_newline,
+            emit_error, escape_html, filter_children_preserving, get_tag_name, is_logical_op,
+            jsx_text_to_str, lit_to_string, make_getter_prop, trim_whitespace,
+        },
+    },
+};
+use swc_core::{
+    common::{DUMMY_SP, comments::Comments},
+    ecma::{ast::*, utils::quote_ident},
+    quote,
+};
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    pub fn transform_element_ssr(
+        &mut self,
+        mut node: JSXElement,
+        info: &TransformInfo,
+    ) -> TemplateInstantiation {
+        let tag_name = get_tag_name(&node);
+        let raw_text = tag_name == "script" || tag_name == "style";
+        let preserve_whitespace =
+            raw_text || info.preserve_whitespace || self.preserves_whitespace(&tag_name);
+        if node
+            .opening
+            .attrs
+            .iter()
+            .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(_)))
+        {
+            return self.create_element_ssr(node, preserve_whitespace);
+        }
+
+        let void_tag = VOID_ELEMENTS.contains(&tag_name.as_str());
+        self.validate_children(&tag_name, &node.children);
+        let mut results = TemplateInstantiation {
+            template: format!("<{tag_name}"),
+            tag_name: tag_name.clone(),
+            is_void: void_tag,
+            preserve_whitespace,
+            ..Default::default()
+        };
+        let mut do_not_escape = raw_text;
+        if let Some((child, raw)) = self.transform_attributes_ssr(node.opening.attrs, &mut results)
+            && node.children.is_empty()
+        {
+            node.children.push(child);
+            do_not_escape |= raw;
+        }
+        results.template += ">";
+        if !void_tag {
+            let (content_part, content_start) =
+                (results.template_parts.len(), results.template.len());
+            self.transform_children_ssr(node.children, &mut results, do_not_escape);
+            // the content may have been split into parts by dynamic values
+            let content = results
+                .template_parts
+                .get_mut(content_part)
+                .unwrap_or(&mut results.template);
+            if drops_leading_newline(&tag_name) && content[content_start..].starts_with('\n') {
+                content.insert(content_start, '\n');
+            }
+            results.template += &format!("</{tag_name}>");
+        }
+        results
+    }
+
+    /// Wraps `expr` in `escape()` wherever it could produce unescaped markup,
+    /// descending into branches so literals and nested templates stay untouched.
+    pub fn escape_expression(&mut self, expr: Expr, attr: bool) -> Expr {
+        match expr {
+            Expr::Lit(Lit::Str(_) | Lit::Num(_)) | Expr::Fn(_) | Expr::Unary(_) => expr,
+            Expr::Tpl(mut tpl) => {
+                tpl.exprs = tpl
+                    .exprs
+                    .into_iter()
+                    .map(|e| Box::new(self.escape_expression(*e, attr)))
+                    .collect();
+                Expr::Tpl(tpl)
+            }
+            Expr::Arrow(mut arrow) => {
+                if let BlockStmtOrExpr::Expr(body) = *arrow.body {
+                    arrow.body = Box::new(BlockStmtOrExpr::Expr(Box::new(
+                        self.escape_expression(*body, attr),
+                    )));
+                }
+                Expr::Arrow(arrow)
+            }
+            Expr::Paren(mut paren) => {
+                paren.expr = Box::new(self.escape_expression(*paren.expr, attr));
+                Expr::Paren(paren)
+            }
+            Expr::Bin(mut bin) if is_logical_op(&bin) => {
+                bin.right = Box::new(self.escape_expression(*bin.right, attr));
+                if bin.op != BinaryOp::LogicalAnd {
+                    bin.left = Box::new(self.escape_expression(*bin.left, attr));
+                }
+                Expr::Bin(bin)
+            }
+            Expr::Bin(mut bin) if bin.op == BinaryOp::Add => {
+                bin.left = Box::new(self.escape_expression(*bin.left, attr));
+                bin.right = Box::new(self.escape_expression(*bin.right, attr));
+                Expr::Bin(bin)
+            }
+            Expr::Bin(_) => expr,
+            Expr::Cond(mut cond) => {
+                cond.cons = Box::new(self.escape_expression(*cond.cons, attr));
+                cond.alt = Box::new(self.escape_expression(*cond.alt, attr));
+                Expr::Cond(cond)
+            }
+            Expr::JSXElement(ref el) if !is_component(&get_tag_name(el)) => expr,
+            _ => {
+                let escape = self.register_import_method("escape");
+                if attr {
+                    quote!("$escape($expr, true)" as Expr, escape = escape, expr: Expr = expr)
+                } else {
+                    quote!("$escape($expr)" as Expr, escape = escape, expr: Expr = expr)
+                }
+            }
+        }
+    }
+
+    fn transform_attributes_ssr(
+        &mut self,
+        attributes: Vec<JSXAttrOrSpread>,
+        results: &mut TemplateInstantiation,
+    ) -> Option<(JSXElementChild, bool)> {
+        let mut children = None;
+        let mut attributes = merge_namespaced_attributes(attributes);
+        let mut class_attributes = take_class_attributes(&mut attributes);
+
+        for (index, attribute) in attributes.into_iter().enumerate() {
+            if class_attributes
+                .as_ref()
+                .is_some_and(|(class_index, _)| *class_index == index)
+            {
+                let (_, class_attributes) = class_attributes.take().unwrap();
+                self.append_merged_class_ssr(results, class_attributes);
+            }
+            let JSXAttrOrSpread::JSXAttr(attribute) = attribute else {
+                continue;
+            };
+
+            let mut reserved_name_space = false;
+            let mut key = match &attribute.name {
+                JSXAttrName::Ident(ident) => ident.sym.to_string(),
+                JSXAttrName::JSXNamespacedName(name) => {
+                    reserved_name_space = RESERVED_NAME_SPACES.contains(name.ns.sym.as_ref());
+                    format!("{}:{}", name.ns.sym, name.name.sym)
+                }
+            };
+            let is_child_prop = CHILD_PROPERTIES.contains(key.as_str());
+
+            let mut value = attribute.value;
+            if reserved_name_space || is_child_prop {
+                value = match value {
+                    Some(JSXAttrValue::Str(s)) => {
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            span: DUMMY_SP,
+                            expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(s)))),
+                        }))
+                    }
+                    None if reserved_name_space => {
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            span: DUMMY_SP,
+                            expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Bool(true.into())))),
+                        }))
+                    }
+                    value => value,
+                };
+            }
+
+            match value {
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    span,
+                })) if reserved_name_space
+                    || is_child_prop
+                    || !matches!(
+                        expr.as_lit(),
+                        Some(Lit::Str(_)) | Some(Lit::Num(_)) | Some(Lit::Bool(_))
+                    ) =>
+                {
+                    if key == "ref"
+                        || key.starts_with("use:")
+                        || key.starts_with("prop:")
+                        || key.starts_with("on")
+                    {
+                        continue;
+                    }
+
+                    if is_child_prop {
+                        children = Some((
+                            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                                span,
+                                expr: JSXExpr::Expr(expr),
+                            }),
+                            key == "innerHTML",
+                        ));
+                        continue;
+                    }
+
+                    if let Some(name) = key.strip_prefix("attr:") {
+                        key = name.to_string();
+                    }
+                    key = ALIASES.get(key.as_str()).map_or(key, |v| v.to_string());
+
+                    if BOOLEANS.contains(&key.as_str()) {
+                        let attribute = self.ssr_attribute(&key, *expr, true);
+                        push_ssr_value(results, attribute);
+                        continue;
+                    }
+
+                    let (key, value, do_escape) = match key.as_str() {
+                        "style" => ("style".to_string(), self.transform_style_ssr(*expr), false),
+                        "classList" => (
+                            "class".to_string(),
+                            quote!(
+                                "$ssr_class_list($value)" as Expr,
+                                ssr_class_list = self.register_import_method("ssrClassList"),
+                                value: Expr = *expr
+                            ),
+                            false,
+                        ),
+                        _ => (key, self.escape_expression(*expr, true), true),
+                    };
+
+                    if !do_escape || value.is_lit() {
+                        results.template += &format!(r#" {key}=""#);
+                        push_ssr_value(results, value);
+                        results.template += "\"";
+                    } else {
+                        let attribute = self.ssr_attribute(&key, value, false);
+                        push_ssr_value(results, attribute);
+                    }
+                }
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })) => {
+                    if let Expr::Lit(lit) = *expr {
+                        append_static_attribute(results, &key, Some(lit));
+                    }
+                }
+                Some(JSXAttrValue::Str(s)) => {
+                    append_static_attribute(results, &key, Some(Lit::Str(s)))
+                }
+                None => append_static_attribute(results, &key, None),
+                _ => {}
+            }
+        }
+        if let Some((_, class_attributes)) = class_attributes {
+            self.append_merged_class_ssr(results, class_attributes);
+        }
+
+        children
+    }
+
+    /// Renders `class`, `className` and `classList` as a single `class`
+    /// attribute, as browsers ignore every `class` after the first.
+    fn append_merged_class_ssr(
+        &mut self,
+        results: &mut TemplateInstantiation,
+        attributes: Vec<JSXAttr>,
+    ) {
+        results.template += r#" class=""#;
+        let mut first = true;
+        for attribute in attributes {
+            let is_class_list =
+                matches!(&attribute.name, JSXAttrName::Ident(name) if &name.sym == "classList");
+            let expr = match attribute.value {
+                Some(JSXAttrValue::Str(s)) => Expr::Lit(Lit::Str(s)),
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })) => *expr,
+                _ => continue,
+            };
+            if !first {
+                results.template += " ";
+            }
+            first = false;
+            match expr {
+                Expr::Lit(lit @ (Lit::Str(_) | Lit::Num(_))) if !is_class_list => {
+                    results.template += &escape_html(&trim_whitespace(&lit_to_string(&lit)), true);
+                }
+                expr if is_class_list => {
+                    let value = quote!(
+                        "$ssr_class_list($value)" as Expr,
+                        ssr_class_list = self.register_import_method("ssrClassList"),
+                        value: Expr = expr
+                    );
+                    push_ssr_value(results, value);
+                }
+                expr => {
+                    let value = self.escape_expression(expr, true);
+                    push_ssr_value(results, value);
+                }
+            }
+        }
+        results.template += "\"";
+    }
+
+    fn ssr_attribute(&mut self, key: &str, value: Expr, is_boolean: bool) -> Expr {
+        quote!(
+            "$ssr_attribute($key, $value, $is_boolean)" as Expr,
+            ssr_attribute = self.register_import_method("ssrAttribute"),
+            key: Expr = key.into(),
+            value: Expr = value,
+            is_boolean: Expr = is_boolean.into()
+        )
+    }
+
+    /// Inlines object literal styles as `"name:" + value` concatenations and
+    /// falls back to the `ssrStyle` runtime helper for anything else.
+    fn transform_style_ssr(&mut self, expr: Expr) -> Expr {
+        let Expr::Object(ObjectLit { props, .. }) = expr else {
+            return quote!(
+                "$ssr_style($value)" as Expr,
+                ssr_style = self.register_import_method("ssrStyle"),
+                value: Expr = expr
+            );
+        };
+
+        let entries = props
+            .iter()
+            .map(|prop| match prop {
+                PropOrSpread::Prop(p) => match &**p {
+                    Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(id),
+                        value,
+                    }) => Some((id.sym.to_string(), *value.clone())),
+                    Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(s),
+                        value,
+                    }) => Some((s.value.to_string_lossy().to_string(), *value.clone())),
+                    Prop::Shorthand(id) => Some((id.sym.to_string(), Expr::Ident(id.clone()))),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let Some(entries) = entries.filter(|entries| !entries.is_empty()) else {
+            return quote!(
+                "$ssr_style($value)" as Expr,
+                ssr_style = self.register_import_method("ssrStyle"),
+                value: Expr = Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props,
+                })
+            );
+        };
+
+        let mut result: Option<Expr> = None;
+        for (i, (name, value)) in entries.into_iter().enumerate() {
+            let prefix: Expr = format!("{}{name}:", if i > 0 { ";" } else { "" }).into();
+            let left = match result {
+                Some(result) => Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: BinaryOp::Add,
+                    left: Box::new(result),
+                    right: Box::new(prefix),
+                }),
+                None => prefix,
+            };
+            result = Some(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::Add,
+                left: Box::new(left),
+                right: Box::new(self.escape_expression(value, true)),
+            }));
+        }
+        result.unwrap()
+    }
+
+    fn transform_children_ssr(
+        &mut self,
+        children: Vec<JSXElementChild>,
+        results: &mut TemplateInstantiation,
+        do_not_escape: bool,
+    ) {
+        for child in children
+            .into_iter()
+            .filter(filter_children_preserving(results.preserve_whitespace))
+        {
+            if let JSXElementChild::JSXFragment(ref fragment) = child {
+                emit_error(
+                    fragment.span,
+                    &format!(
+                        "Fragments can only be used top level in JSX. Not used under a <{}>.",
+                        results.tag_name
+                    ),
+                );
+                continue;
+            }
+            let is_native = matches!(
+                &child,
+                JSXElementChild::JSXElement(el) if !is_component(&get_tag_name(el))
+            );
+            let Some(mut transformed) = self.transform_node(
+                child,
+                &TransformInfo {
+                    skip_id: true,
+                    do_not_escape,
+                    preserve_whitespace: results.preserve_whitespace,
+                    ..Default::default()
+                },
+            ) else {
+                continue;
+            };
+
+            let exprs = std::mem::take(&mut transformed.exprs);
+            append_ssr_template(results, transformed);
+            if !exprs.is_empty() {
+                let expr = exprs.into_first();
+                let expr = if do_not_escape || is_native {
+                    expr
+                } else {
+                    self.escape_expression(expr, false)
+                };
+                push_ssr_value(results, expr);
+            }
+        }
+    }
+
+    /// Elements with spread attributes can't be split into a static template,
+    /// so they are rendered at runtime through `ssrElement`.
+    fn create_element_ssr(
+        &mut self,
+        node: JSXElement,
+        preserve_whitespace: bool,
+    ) -> TemplateInstantiation {
+        let tag_name = get_tag_name(&node);
+        let has_children = !node.children.is_empty();
+
+        let child_nodes: Vec<Expr> = node
+            .children
+            .into_iter()
+            .filter(filter_children_preserving(preserve_whitespace))
+            .enumerate()
+            .filter_map(|(index, child)| match child {
+                JSXElementChild::JSXText(text) => {
+                    let mut value = if preserve_whitespace {
+                        text.value.replace('\r', "")
+                    } else {
+                        jsx_text_to_str(&text.value)
+                    };
+                    if index == 0 && drops_leading_newline(&tag_name) && value.starts_with('\n') {
+                        value.insert(0, '\n');
+                    }
+                    (!value.is_empty()).then(|| Expr::Lit(Lit::Str(value.into())))
+                }
+                child => {
+                    let is_native = matches!(
+                        &child,
+                        JSXElementChild::JSXElement(el) if !is_component(&get_tag_name(el))
+                    );
+                    let mut transformed = self.transform_node(
+                        child,
+                        &TransformInfo {
+                            skip_id: true,
+                            preserve_whitespace,
+                            ..Default::default()
+                        },
+                    )?;
+                    if !is_native && !transformed.exprs.is_empty() {
+                        let expr = transformed.exprs.remove(0);
+                        transformed
+                            .exprs
+                            .insert(0, self.escape_expression(expr, false));
+                    }
+                    Some(self.create_template_ssr(transformed))
+                }
+            })
+            .collect();
+
+        let mut props = vec![];
+        let mut running_objects = vec![];
+        let mut dynamic_spread = false;
+        for attribute in node.opening.attrs {
+            match attribute {
+                JSXAttrOrSpread::SpreadElement(spread) => {
+                    if !running_objects.is_empty() {
+                        props.push(Expr::Object(ObjectLit {
+                            span: DUMMY_SP,
+                            props: std::mem::take(&mut running_objects),
+                        }));
+                    }
+
+                    let expr = if self.is_dynamic(&spread.expr, None, true, false, true, false) {
+                        dynamic_spread = true;
+                        match *spread.expr {
+                            Expr::Call(CallExpr {
+                                callee: Callee::Expr(callee_expr),
+                                args,
+                                ..
+                            }) if args.is_empty()
+                                && !matches!(*callee_expr, Expr::Call(_) | Expr::Member(_)) =>
+                            {
+                                *callee_expr
+                            }
+                            expr => quote!("() => $expr" as Expr, expr: Expr = expr),
+                        }
+                    } else {
+                        *spread.expr
+                    };
+                    props.push(expr);
+                }
+                JSXAttrOrSpread::JSXAttr(attr) => {
+                    let (id, key) = convert_jsx_identifier(&attr.name);
+                    if (has_children && key == "children")
+                        || key == "ref"
+                        || key.starts_with("use:")
+                        || key.starts_with("prop:")
+                        || key.starts_with("on")
+                    {
+                        continue;
+                    }
+
+                    let prop = match attr.value {
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(expr),
+                            ..
+                        })) => {
+                            if self.is_dynamic(&expr, None, true, true, true, false) {
+                                make_getter_prop(id, *expr)
+                            } else {
+                                Prop::KeyValue(KeyValueProp {
+                                    key: id,
+                                    value: expr,
+                                })
+                            }
+                        }
+                        Some(JSXAttrValue::Str(s)) => Prop::KeyValue(KeyValueProp {
+                            key: id,
+                            value: Lit::Str(s).into(),
+                        }),
+                        Some(JSXAttrValue::JSXElement(el)) => Prop::KeyValue(KeyValueProp {
+                            key: id,
+                            value: Box::new(Expr::JSXElement(el)),
+                        }),
+                        Some(JSXAttrValue::JSXFragment(frag)) => Prop::KeyValue(KeyValueProp {
+                            key: id,
+                            value: Box::new(Expr::JSXFragment(frag)),
+                        }),
+                        _ => Prop::KeyValue(KeyValueProp {
+                            key: id,
+                            value: Lit::Bool(true.into()).into(),
+                        }),
+                    };
+                    running_objects.push(PropOrSpread::Prop(Box::new(prop)));
+                }
+            }
+        }
+
+        if !running_objects.is_empty() || props.is_empty() {
+            props.push(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: running_objects,
+            }));
+        }
+
+        let props = if props.len() > 1 || dynamic_spread {
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(self.register_import_method("mergeProps").into()),
+                args: props.into_iter().map(|p| p.into()).collect(),
+                ..Default::default()
+            })
+        } else {
+            props.into_first()
+        };
+
+        let children = match child_nodes.len() {
+            0 => Expr::Ident(quote_ident!("undefined").into()),
+            1 => child_nodes.into_first(),
+            _ => Expr::Array(ArrayLit {
+                span: DUMMY_SP,
+                elems: child_nodes
+                    .into_iter()
+                    .map(|expr| Some(expr.into()))
+                    .collect(),
+            }),
+        };
+
+        let ssr_element = self.register_import_method("ssrElement");
+        TemplateInstantiation {
+            exprs: vec![quote!(
+                "$ssr_element($tag_name, $props, $children, false)" as Expr,
+                ssr_element = ssr_element,
+                tag_name: Expr = tag_name.clone().into(),
+                props: Expr = props,
+                children: Expr = children
+            )],
+            tag_name,
+            ..Default::default()
+        }
+    }
+}
+
+fn append_static_attribute(results: &mut TemplateInstantiation, key: &str, value: Option<Lit>) {
+    if key == "$ServerOnly" {
+        return;
+    }
+    let key = ALIASES.get(key).copied().unwrap_or(key);
+    match value {
+        None | Some(Lit::Bool(Bool { value: true, .. })) => {
+            results.template += &format!(" {key}");
+        }
+        Some(Lit::Bool(_)) => {}
+        Some(lit) => {
+            let mut text = lit_to_string(&lit);
+            if text.is_empty() {
+                results.template += &format!(" {key}");
+                return;
+            }
+            if key == "style" || key == "class" {
+                text = trim_whitespace(&text);
+                if key == "style" {
+                    text = text.replace("; ", ";").replace(": ", ":");
+                }
+            }
+            results.template += &format!(r#" {key}="{}""#, escape_html(&text, true));
+        }
+    }
+}
+
+fn is_class_attribute(attribute: &JSXAttrOrSpread) -> bool {
+    matches!(
+        attribute,
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            ..
+        }) if matches!(name.sym.as_ref(), "class" | "className" | "classList")
+    )
+}
+
+/// Removes the class attributes when there is more than one of them, returning
+/// them with the position of the first so they can be rendered together.
+fn take_class_attributes(attributes: &mut Vec<JSXAttrOrSpread>) -> Option<(usize, Vec<JSXAttr>)> {
+    if attributes.iter().filter(|a| is_class_attribute(a)).count() < 2 {
+        return None;
+    }
+    let index = attributes.iter().position(is_class_attribute)?;
+    let mut class_attributes = vec![];
+    attributes.retain(|attribute| match attribute {
+        JSXAttrOrSpread::JSXAttr(attr) if is_class_attribute(attribute) => {
+            class_attributes.push(attr.clone());
+            false
+        }
+        _ => true,
+    });
+    Some((index, class_attributes))
+}
+
+/// Folds `style:*` and `class:*` attributes into the element's `style` and
+/// `classList` objects so each renders through a single attribute.
+fn merge_namespaced_attributes(attributes: Vec<JSXAttrOrSpread>) -> Vec<JSXAttrOrSpread> {
+    let mut styles = vec![];
+    let mut classes = vec![];
+    let mut rest = vec![];
+    for attribute in attributes {
+        match attribute {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::JSXNamespacedName(name),
+                value,
+                ..
+            }) if &name.ns.sym == "style" || &name.ns.sym == "class" => {
+                let value = match value {
+                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    })) => *expr,
+                    Some(JSXAttrValue::Str(s)) => Expr::Lit(Lit::Str(s)),
+                    _ => Expr::Lit(Lit::Bool(true.into())),
+                };
+                let prop = PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(name.name.sym.to_string().into()),
+                    value: Box::new(value),
+                })));
+                if &name.ns.sym == "style" {
+                    styles.push(prop);
+                } else {
+                    classes.push(prop);
+                }
+            }
+            attribute => rest.push(attribute),
+        }
+    }
+    extend_object_attribute(&mut rest, "style", styles);
+    extend_object_attribute(&mut rest, "classList", classes);
+    rest
+}
+
+fn extend_object_attribute(
+    attributes: &mut Vec<JSXAttrOrSpread>,
+    key: &str,
+    props: Vec<PropOrSpread>,
+) {
+    if props.is_empty() {
+        return;
+    }
+    let existing = attributes.iter_mut().find_map(|attribute| match attribute {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            value:
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })),
+            ..
+        }) if name.sym == key => match &mut **expr {
+            Expr::Object(obj) if !obj.props.iter().any(|p| p.is_spread()) => Some(obj),
+            _ => None,
+        },
+        _ => None,
+    });
+    if let Some(obj) = existing {
+        obj.props.extend(props);
+    } else {
+        attributes.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: DUMMY_SP,
+            name: JSXAttrName::Ident(quote_ident!(key)),
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props,
+                }))),
+            })),
+        }));
+    }
+}
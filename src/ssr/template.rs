@@ -0,0 +1,119 @@
+use crate::{
+    TransformVisitor,
+    shared::{
+        structs::{TemplateConstruction, TemplateInstantiation},
+        utils::{IntoFirst, ProgramItem, insert_after_imports},
+    },
+};
+use swc_core::{
+    common::{DUMMY_SP, comments::Comments},
+    ecma::ast::*,
+};
+
+/// Appends a child result to the template being built, keeping the
+/// parts/values interleaving intact.
+pub fn append_ssr_template(results: &mut TemplateInstantiation, child: TemplateInstantiation) {
+    let mut parts = child.template_parts.into_iter();
+    if let Some(first) = parts.next() {
+        results.template += &first;
+        results
+            .template_parts
+            .push(std::mem::take(&mut results.template));
+        results.template_parts.extend(parts);
+        results.template = child.template;
+    } else {
+        results.template += &child.template;
+    }
+    results.template_values.extend(child.template_values);
+}
+
+/// Closes the current template part and registers `value` to be rendered after it.
+pub fn push_ssr_value(results: &mut TemplateInstantiation, value: Expr) {
+    results
+        .template_parts
+        .push(std::mem::take(&mut results.template));
+    results.template_values.push(value);
+}
+
+fn make_template_expr(template: &TemplateConstruction) -> Expr {
+    if template.template_parts.is_empty() {
+        return Expr::Lit(Lit::Str(template.template.clone().into()));
+    }
+    Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: template
+            .template_parts
+            .iter()
+            .chain([&template.template])
+            .map(|part| Some(Expr::Lit(Lit::Str(part.clone().into())).into()))
+            .collect(),
+    })
+}
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    pub fn create_template_ssr(&mut self, result: TemplateInstantiation) -> Expr {
+        if result.template.is_empty() && result.template_parts.is_empty() {
+            return result.exprs.into_first();
+        }
+
+        let template_def = self.templates.iter().find(|t| {
+            t.template == result.template && t.template_parts == result.template_parts
+        });
+        let template_id = if let Some(template_def) = template_def {
+            template_def.id.clone()
+        } else {
+            let template_id = self.generate_template_identifier(&format!(
+                "{}{}",
+                result.template,
+                result.template_parts.join("\0")
+            ));
+            self.templates.push(TemplateConstruction {
+                id: template_id.clone(),
+                template: result.template,
+                template_parts: result.template_parts,
+                is_svg: false,
+                is_ce: false,
+            });
+            template_id
+        };
+
+        let ssr = self.register_import_method("ssr");
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(ssr))),
+            args: [Expr::Ident(template_id)]
+                .into_iter()
+                .chain(result.template_values)
+                .map(|x| x.into())
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    pub fn append_templates_ssr<T: ProgramItem>(&mut self, body: &mut Vec<T>) {
+        if self.templates.is_empty() {
+            return;
+        }
+        insert_after_imports(
+            body,
+            T::from(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: std::mem::take(&mut self.templates)
+                    .into_iter()
+                    .map(|template| VarDeclarator {
+                        span: DUMMY_SP,
+                        init: Some(Box::new(make_template_expr(&template))),
+                        name: template.id.into(),
+                        definite: false,
+                    })
+                    .collect(),
+                ..Default::default()
+            })))),
+        )
+    }
+}
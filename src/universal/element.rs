@@ -0,0 +1,318 @@
+use crate::{
+    TransformVisitor,
+    shared::{
+        structs::{DynamicAttr, TemplateInstantiation},
+        transform::TransformInfo,
+        utils::{
+            check_length, emit_error, filter_children, get_tag_name, make_var_declarator,
+            trim_whitespace, with_span,
+        },
+    },
+};
+use swc_core::{
+    common::comments::Comments,
+    ecma::{ast::*, utils::quote_ident},
+    quote,
+};
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    pub fn transform_element_universal(&mut self, mut node: JSXElement) -> TemplateInstantiation {
+        let tag_name = get_tag_name(&node);
+        let id = self.generate_uid_identifier("el$");
+        let create_element = self.register_import_method("createElement");
+        let mut results = TemplateInstantiation {
+            id: Some(id.clone()),
+            tag_name: tag_name.clone(),
+            declarations: vec![make_var_declarator(
+                id,
+                quote!(
+                    "$create_element($tag_name)" as Expr,
+                    create_element = create_element,
+                    tag_name: Expr = tag_name.into()
+                ),
+            )],
+            ..Default::default()
+        };
+
+        let child = self.transform_attributes_universal(
+            node.opening.attrs,
+            !node.children.is_empty(),
+            &mut results,
+        );
+        if let Some(child) = child
+            && node.children.is_empty()
+        {
+            node.children.push(child);
+        }
+        self.transform_children_universal(node.children, &mut results);
+        results
+    }
+
+    pub fn set_prop(
+        &mut self,
+        elem: Ident,
+        name: &str,
+        value: Expr,
+        prev_id: Option<Expr>,
+    ) -> Expr {
+        let set_prop = self.register_import_method("setProp");
+        if let Some(prev_id) = prev_id {
+            quote!(
+                "$set_prop($elem, $name, $value, $prev_id)" as Expr,
+                set_prop = set_prop,
+                elem = elem,
+                name: Expr = name.into(),
+                value: Expr = value,
+                prev_id: Expr = prev_id
+            )
+        } else {
+            quote!(
+                "$set_prop($elem, $name, $value)" as Expr,
+                set_prop = set_prop,
+                elem = elem,
+                name: Expr = name.into(),
+                value: Expr = value
+            )
+        }
+    }
+
+    fn transform_attributes_universal(
+        &mut self,
+        mut attributes: Vec<JSXAttrOrSpread>,
+        has_children: bool,
+        results: &mut TemplateInstantiation,
+    ) -> Option<JSXElementChild> {
+        let elem = results.id.clone().unwrap();
+        let mut children = None;
+        let mut spread_expr = None;
+
+        if attributes
+            .iter()
+            .any(|attribute| matches!(attribute, JSXAttrOrSpread::SpreadElement(_)))
+        {
+            let props;
+            (attributes, props) =
+                self.process_spread_props(attributes, self.config.wrap_conditionals);
+            spread_expr = Some(quote!(
+                "$spread($elem, $props, $has_children)" as Expr,
+                spread = self.register_import_method("spread"),
+                elem = elem.clone(),
+                props: Expr = props,
+                has_children: Expr = has_children.into()
+            ));
+        }
+
+        for attribute in attributes {
+            let JSXAttrOrSpread::JSXAttr(attribute) = attribute else {
+                continue;
+            };
+            let key = match &attribute.name {
+                JSXAttrName::Ident(ident) => ident.sym.to_string(),
+                JSXAttrName::JSXNamespacedName(name) => {
+                    format!("{}:{}", name.ns.sym, name.name.sym)
+                }
+            };
+
+            let value = match attribute.value {
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    span,
+                })) => {
+                    if key == "ref" {
+                        self.transform_element_ref(*expr, results);
+                        continue;
+                    }
+                    if let Some(name) = key.strip_prefix("use:") {
+                        let use_hook = self.register_import_method("use");
+                        results.exprs.insert(
+                            0,
+                            with_span(
+                                quote!(
+                                    "$use_hook($name, $elem, () => $arrow)" as Expr,
+                                    use_hook = use_hook,
+                                    name: Ident = quote_ident!(name).into(),
+                                    elem = elem.clone(),
+                                    arrow: Expr = *expr
+                                ),
+                                attribute.span,
+                            ),
+                        );
+                        continue;
+                    }
+                    if key == "children" {
+                        children = Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+                            span,
+                            expr: JSXExpr::Expr(expr),
+                        }));
+                        continue;
+                    }
+                    if !self.config.effect_wrapper.is_empty()
+                        && self.is_dynamic(&expr, Some(span), true, false, true, false)
+                    {
+                        results.dynamics.push(DynamicAttr {
+                            elem: elem.clone(),
+                            key,
+                            value: *expr,
+                            is_svg: false,
+                            is_ce: false,
+                            tag_name: results.tag_name.clone(),
+                            span: attribute.span,
+                        });
+                        continue;
+                    }
+                    *expr
+                }
+                Some(JSXAttrValue::Str(s)) => Expr::Lit(Lit::Str(
+                    html_escape::decode_html_entities(&s.value.to_string_lossy()).into(),
+                )),
+                Some(JSXAttrValue::JSXElement(el)) => Expr::JSXElement(el),
+                Some(JSXAttrValue::JSXFragment(frag)) => Expr::JSXFragment(frag),
+                None
+                | Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::JSXEmptyExpr(_),
+                    ..
+                })) => Expr::Lit(Lit::Bool(true.into())),
+            };
+            let set_prop = self.set_prop(elem.clone(), &key, value, None);
+            results.exprs.push(with_span(set_prop, attribute.span));
+        }
+
+        if let Some(spread_expr) = spread_expr {
+            results.exprs.push(spread_expr);
+        }
+
+        children
+    }
+
+    fn transform_children_universal(
+        &mut self,
+        children: Vec<JSXElementChild>,
+        results: &mut TemplateInstantiation,
+    ) {
+        let filtered_children = children
+            .into_iter()
+            .filter(filter_children)
+            .collect::<Vec<JSXElementChild>>();
+        let multi = check_length(&filtered_children, false);
+
+        let mut child_nodes: Vec<TemplateInstantiation> = vec![];
+        for child in filtered_children {
+            if let JSXElementChild::JSXFragment(ref fragment) = child {
+                emit_error(
+                    fragment.span,
+                    &format!(
+                        "Fragments can only be used top level in JSX. Not used under a <{}>.",
+                        results.tag_name
+                    ),
+                );
+                continue;
+            }
+
+            // there is no markup to parse, so text is passed to the renderer verbatim
+            let text = match &child {
+                JSXElementChild::JSXText(text) => Some(
+                    html_escape::decode_html_entities(&trim_whitespace(&text.value)).to_string(),
+                ),
+                _ => self.get_static_expression(&child),
+            };
+            if let Some(text) = text {
+                if text.is_empty() {
+                    continue;
+                }
+                if let Some(last) = child_nodes.last_mut()
+                    && last.text
+                {
+                    last.template += &text;
+                    continue;
+                }
+                child_nodes.push(TemplateInstantiation {
+                    id: Some(self.generate_uid_identifier("el$")),
+                    template: text,
+                    text: true,
+                    ..Default::default()
+                });
+                continue;
+            }
+
+            if let Some(transformed) = self.transform_node(child, &TransformInfo::default()) {
+                child_nodes.push(transformed);
+            }
+        }
+
+        let n = child_nodes.len();
+        let next_children: Vec<Option<Ident>> = {
+            let mut result = vec![None; n];
+            let mut next_id: Option<&Ident> = None;
+            for i in (0..n).rev() {
+                result[i] = next_id.cloned();
+                if let Some(ref id) = child_nodes[i].id {
+                    next_id = Some(id);
+                }
+            }
+            result
+        };
+
+        let elem = results.id.clone().unwrap();
+        let mut appends = vec![];
+        for (index, child) in child_nodes.into_iter().enumerate() {
+            if let Some(id) = child.id {
+                let node = if child.text {
+                    let text_node = quote!(
+                        "$create_text_node($text)" as Expr,
+                        create_text_node = self.register_import_method("createTextNode"),
+                        text: Expr = child.template.into()
+                    );
+                    if multi {
+                        results
+                            .declarations
+                            .push(make_var_declarator(id.clone(), text_node));
+                        Expr::Ident(id)
+                    } else {
+                        text_node
+                    }
+                } else {
+                    Expr::Ident(id)
+                };
+                appends.push(quote!(
+                    "$insert_node($elem, $node)" as Expr,
+                    insert_node = self.register_import_method("insertNode"),
+                    elem = elem.clone(),
+                    node: Expr = node
+                ));
+                results.declarations.extend(child.declarations);
+                results.exprs.extend(child.exprs);
+                results.dynamics.extend(child.dynamics);
+                results.post_exprs.extend(child.post_exprs);
+            } else if !child.exprs.is_empty() {
+                let insert = self.register_import_method("insert");
+                let child_span = child.span;
+                let child_expr = child.exprs.into_iter().next().unwrap();
+                let insert_expr = if multi {
+                    let next_child = next_children[index]
+                        .clone()
+                        .map(Expr::Ident)
+                        .unwrap_or(quote!("null" as Expr));
+                    quote!(
+                        "$insert($elem, $child_expr, $next_child)" as Expr,
+                        insert = insert,
+                        elem = elem.clone(),
+                        child_expr: Expr = child_expr,
+                        next_child: Expr = next_child
+                    )
+                } else {
+                    quote!(
+                        "$insert($elem, $child_expr)" as Expr,
+                        insert = insert,
+                        elem = elem.clone(),
+                        child_expr: Expr = child_expr
+                    )
+                };
+                results.exprs.push(with_span(insert_expr, child_span));
+            }
+        }
+        results.exprs.splice(0..0, appends);
+    }
+}
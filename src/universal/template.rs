@@ -0,0 +1,163 @@
+use crate::{
+    TransformVisitor,
+    shared::{
+        structs::{DynamicAttr, TemplateInstantiation},
+        utils::{IntoFirst, make_iife, make_var_declarator, with_span},
+    },
+};
+use swc_core::{
+    common::{DUMMY_SP, comments::Comments},
+    ecma::{
+        ast::*,
+        utils::{ExprFactory, quote_ident},
+    },
+    quote,
+};
+
+impl<C> TransformVisitor<C>
+where
+    C: Comments,
+{
+    pub fn create_template_universal(&mut self, result: TemplateInstantiation, wrap: bool) -> Expr {
+        if let Some(id) = result.id {
+            if result.exprs.is_empty()
+                && result.dynamics.is_empty()
+                && result.post_exprs.is_empty()
+                && result.declarations.len() == 1
+            {
+                return with_span(*result.declarations.into_first().init.unwrap(), result.span);
+            }
+            let stmts = [VarDecl {
+                kind: VarDeclKind::Const,
+                decls: result.declarations,
+                ..Default::default()
+            }
+            .into()]
+            .into_iter()
+            .chain(result.exprs.into_iter().map(|x| x.into_stmt()))
+            .chain(
+                self.wrap_dynamics_universal(result.dynamics)
+                    .map(|x| x.into_stmt()),
+            )
+            .chain(result.post_exprs.into_iter().map(|x| x.into_stmt()))
+            .chain([id.into_return_stmt().into()])
+            .collect();
+            return with_span(make_iife(stmts), result.span);
+        }
+
+        let dynamic = result.dynamic;
+        let expr = result.exprs.into_first();
+        if wrap && dynamic && !self.config.memo_wrapper.is_empty() {
+            return quote!(
+                "$memo_wrapper($my_fn)" as Expr,
+                memo_wrapper = self.register_import_method(&self.config.memo_wrapper.clone()),
+                my_fn: Expr = expr
+            );
+        }
+
+        expr
+    }
+
+    fn wrap_dynamics_universal(&mut self, mut dynamics: Vec<DynamicAttr>) -> Option<Expr> {
+        if dynamics.is_empty() {
+            return None;
+        }
+
+        let effect_wrapper_id = self.register_import_method(&self.config.effect_wrapper.clone());
+
+        if dynamics.len() == 1 {
+            let attr = dynamics.pop().unwrap();
+            let prev_value = Ident::new_no_ctxt("_$p".into(), DUMMY_SP);
+            let set_prop = self.set_prop(
+                attr.elem,
+                &attr.key,
+                attr.value,
+                Some(Expr::Ident(prev_value.clone())),
+            );
+            return Some(with_span(
+                quote!(
+                    "$effect_wrapper(($params) => $set_prop)" as Expr,
+                    effect_wrapper = effect_wrapper_id,
+                    params: Pat = prev_value.into(),
+                    set_prop: Expr = with_span(set_prop, attr.span)
+                ),
+                attr.span,
+            ));
+        }
+
+        let mut decls = vec![];
+        let mut statements = vec![];
+        let mut identifiers = vec![];
+        let prev_id = Ident::new_no_ctxt("_p$".into(), DUMMY_SP);
+        let span = dynamics[0].span;
+
+        for attr in dynamics {
+            let identifier = self.generate_uid_identifier("v$");
+            identifiers.push(identifier.clone());
+            decls.push(VarDeclarator {
+                span: attr.span,
+                ..make_var_declarator(identifier.clone(), attr.value)
+            });
+
+            let obj_member = prev_id.clone().make_member(identifier.clone().into());
+            let setter = self.set_prop(
+                attr.elem,
+                &attr.key,
+                Expr::Ident(identifier.clone()),
+                Some(obj_member.clone().into()),
+            );
+            statements.push(quote!(
+                "$val !== $obj && $assign" as Stmt,
+                val = identifier,
+                obj: Expr = obj_member.clone().into(),
+                assign: Expr = Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: AssignOp::Assign,
+                    left: obj_member.into(),
+                    right: Box::new(setter),
+                })
+            ));
+        }
+
+        let effect_fn = Expr::Arrow(ArrowExpr {
+            span: DUMMY_SP,
+            params: vec![Pat::Ident(prev_id.clone().into())],
+            body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                stmts: [Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Const,
+                    declare: false,
+                    decls,
+                    ..Default::default()
+                })))]
+                .into_iter()
+                .chain(statements)
+                .chain([prev_id.into_return_stmt().into()])
+                .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+        let effect_obj = Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: identifiers
+                .into_iter()
+                .map(|id| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(id.into()),
+                        value: quote_ident!("undefined").into(),
+                    })))
+                })
+                .collect(),
+        });
+        Some(with_span(
+            quote!(
+                "$effect_wrapper($my_fn, $obj)" as Expr,
+                effect_wrapper = effect_wrapper_id,
+                my_fn: Expr = effect_fn,
+                obj: Expr = effect_obj
+            ),
+            span,
+        ))
+    }
+}
@@ -0,0 +1,108 @@
+use jsx_dom_expressions::config::{Config, Generate, Hmr};
+
+#[test]
+fn parses_generate() {
+    let config = Config::from_json(r#"{ "generate": "ssr", "moduleName": "r-server" }"#).unwrap();
+    assert_eq!(config.generate, Generate::Ssr);
+    assert_eq!(config.module_name, "r-server");
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    let err = Config::from_json(r#"{ "delegateEvent": false }"#).err().unwrap();
+    assert!(err.contains("unknown field `delegateEvent`"), "{err}");
+    assert!(err.contains("`delegateEvents`"), "{err}");
+}
+
+#[test]
+fn rejects_unknown_generate() {
+    let err = Config::from_json(r#"{ "generate": "srr" }"#).err().unwrap();
+    assert!(err.contains("unknown variant `srr`"), "{err}");
+    assert!(err.contains("`dom`, `ssr`, `universal`, `dynamic`"), "{err}");
+}
+
+#[test]
+fn filters_files_with_globs() {
+    let config = Config::from_json(
+        r#"{ "include": ["src/**/*.jsx", "src/**/*.tsx"], "exclude": ["**/*.stories.tsx", "**/vendor/**"] }"#,
+    )
+    .unwrap();
+    assert!(config.should_transform("src/App.jsx"));
+    assert!(config.should_transform("src/components/Button.tsx"));
+    assert!(!config.should_transform("src/components/Button.stories.tsx"));
+    assert!(!config.should_transform("src/vendor/widget.jsx"));
+    assert!(!config.should_transform("scripts/build.js"));
+    assert!(Config::default().should_transform("anything.jsx"));
+}
+
+#[test]
+fn matches_paths_relative_to_cwd() {
+    let config = Config::from_json(r#"{ "include": ["src/**/*.jsx"] }"#).unwrap();
+    assert!(config.should_transform_in("/app/src/App.jsx", Some("/app")));
+    assert!(config.should_transform_in("/app/src/App.jsx", Some("/app/")));
+    assert!(!config.should_transform_in("/app2/src/App.jsx", Some("/app")));
+    assert!(config.should_transform_in("src/App.jsx", None));
+}
+
+#[test]
+fn keeps_single_star_within_a_directory() {
+    let config = Config::from_json(r#"{ "include": ["src/*.jsx"] }"#).unwrap();
+    assert!(config.should_transform("src/App.jsx"));
+    assert!(!config.should_transform("src/components/Button.jsx"));
+
+    let config = Config::from_json(r#"{ "staticModules": ["./constants/*"] }"#).unwrap();
+    assert!(config.is_static_module("./constants/colors"));
+    assert!(!config.is_static_module("./constants/theme/colors"));
+}
+
+#[test]
+fn rejects_invalid_globs() {
+    let err = Config::from_json(r#"{ "exclude": ["src/[a-"] }"#).err().unwrap();
+    assert!(err.contains("src/[a-"), "{err}");
+}
+
+#[test]
+#[should_panic(expected = "src/[a-")]
+fn panics_on_invalid_globs_outside_from_json() {
+    let config = Config {
+        exclude: vec!["src/[a-".to_string()],
+        ..Default::default()
+    };
+    config.should_transform("src/App.jsx");
+}
+
+#[test]
+fn parses_hmr() {
+    let config = Config::from_json(r#"{ "dev": true, "hmr": "rspack-esm" }"#).unwrap();
+    assert_eq!(config.hmr, Some(Hmr::RspackEsm));
+    assert_eq!(Config::from_json("{}").unwrap().hmr, None);
+}
+
+#[test]
+fn rejects_hmr_outside_dev() {
+    let err = Config::from_json(r#"{ "hmr": "vite" }"#).err().unwrap();
+    assert!(err.contains("requires `dev: true`"), "{err}");
+}
+
+#[test]
+fn rejects_unknown_hmr() {
+    let err = Config::from_json(r#"{ "hmr": "webpack" }"#).err().unwrap();
+    assert!(err.contains("unknown variant `webpack`"), "{err}");
+    assert!(err.contains("`esm`, `standard`, `vite`, `webpack5`, `rspack-esm`"), "{err}");
+}
+
+#[test]
+fn rejects_unknown_helper_import_keys() {
+    let err = Config::from_json(
+        r#"{ "helperImports": { "insert": { "moduleName": "x", "importNme": "y" } } }"#,
+    )
+    .err()
+    .unwrap();
+    assert!(err.contains("HelperImport"), "{err}");
+
+    let config = Config::from_json(
+        r#"{ "helperImports": { "insert": { "moduleName": "x", "importName": "y" } } }"#,
+    )
+    .unwrap();
+    assert_eq!(config.helper_imports["insert"].import_name("insert"), "y");
+}
@@ -1,13 +1,13 @@
 use std::path::PathBuf;
 
 use jsx_dom_expressions::TransformVisitor;
-use jsx_dom_expressions::config::Config;
+use jsx_dom_expressions::config::{Config, Generate, Hmr, RendererConfig, RequireImportSource};
 use swc_core::common::Mark;
 use swc_core::ecma::visit::visit_mut_pass;
 use swc_core::{
     ecma::parser::{EsSyntax, Syntax},
     ecma::transforms::base::resolver,
-    ecma::transforms::testing::test_fixture,
+    ecma::transforms::testing::{FixtureTestConfig, test_fixture},
 };
 use testing::fixture;
 
@@ -18,8 +18,11 @@ fn syntax() -> Syntax {
     })
 }
 
-#[fixture("tests/fixture/babel/**/code.js")]
-fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
+fn run_fixture(input: PathBuf, config: fn() -> Config) {
+    run_fixture_with(input, config, Default::default());
+}
+
+fn run_fixture_with(input: PathBuf, config: fn() -> Config, test_config: FixtureTestConfig) {
     let output = input.parent().unwrap().join("output.js");
 
     test_fixture(
@@ -27,15 +30,33 @@ fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
         &|t| {
             (
                 resolver(Mark::new(), Mark::new(), false),
-                visit_mut_pass(TransformVisitor::new(
-                    Config {
-                        module_name: "r-dom".to_string(),
-                        built_ins: vec!["For".to_string(), "Show".to_string()],
-                        context_to_custom_elements: true,
-                        ..Default::default()
-                    },
-                    t.comments.clone(),
-                )),
+                visit_mut_pass(TransformVisitor::new(config(), t.comments.clone())),
+            )
+        },
+        &input,
+        &output,
+        test_config,
+    );
+}
+
+/// Runs with the source map attached, reporting locations relative to the crate root.
+fn run_dev_fixture(input: PathBuf, config: fn() -> Config) {
+    let output = input.parent().unwrap().join("output.js");
+    let filename = input
+        .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(&input)
+        .to_string_lossy()
+        .into_owned();
+
+    test_fixture(
+        syntax(),
+        &|t| {
+            (
+                resolver(Mark::new(), Mark::new(), false),
+                visit_mut_pass(
+                    TransformVisitor::new(config(), t.comments.clone())
+                        .with_source_map(Some(filename.clone()), t.cm.clone()),
+                ),
             )
         },
         &input,
@@ -43,3 +64,272 @@ fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
         Default::default(),
     );
 }
+
+#[fixture("tests/fixture/babel/**/code.js")]
+fn jsx_dom_expressions_fixture_babel(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        context_to_custom_elements: true,
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/ssr/**/code.js")]
+fn jsx_dom_expressions_fixture_ssr(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-server".to_string(),
+        generate: Generate::Ssr,
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/hydratable/**/code.js")]
+fn jsx_dom_expressions_fixture_hydratable(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        hydratable: true,
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/universal/**/code.js")]
+fn jsx_dom_expressions_fixture_universal(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-custom".to_string(),
+        generate: Generate::Universal,
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/dynamic/**/code.js")]
+fn jsx_dom_expressions_fixture_dynamic(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        generate: Generate::Dynamic,
+        renderers: vec![
+            RendererConfig {
+                name: "dom".to_string(),
+                module_name: "r-dom".to_string(),
+                elements: vec!["div".to_string(), "span".to_string(), "button".to_string()],
+            },
+            RendererConfig {
+                name: "universal".to_string(),
+                module_name: "r-custom".to_string(),
+                elements: vec![],
+            },
+        ],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/dynamic-renderers/**/code.js")]
+fn jsx_dom_expressions_fixture_dynamic_renderers(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        generate: Generate::Dynamic,
+        renderers: vec![
+            RendererConfig {
+                name: "dom".to_string(),
+                module_name: "r-dom".to_string(),
+                elements: vec!["div".to_string()],
+            },
+            RendererConfig {
+                name: "canvas".to_string(),
+                module_name: "my-canvas".to_string(),
+                elements: vec!["mesh".to_string(), "text".to_string()],
+            },
+            RendererConfig {
+                name: "hud".to_string(),
+                module_name: "my-hud".to_string(),
+                elements: vec!["panel".to_string(), "label".to_string()],
+            },
+        ],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/import-source/**/code.js")]
+fn jsx_dom_expressions_fixture_import_source(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        require_import_source: RequireImportSource::Source("r-dom".to_string()),
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/validate/**/code.js")]
+fn jsx_dom_expressions_fixture_validate(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            module_name: "r-dom".to_string(),
+            ..Default::default()
+        },
+        FixtureTestConfig {
+            allow_error: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[fixture("tests/fixture/errors/**/code.js")]
+fn jsx_dom_expressions_fixture_errors(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            module_name: "r-dom".to_string(),
+            ..Default::default()
+        },
+        FixtureTestConfig {
+            allow_error: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[fixture("tests/fixture/script/**/code.js")]
+fn jsx_dom_expressions_fixture_script(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            module_name: "r-dom".to_string(),
+            built_ins: vec!["For".to_string(), "Show".to_string()],
+            ..Default::default()
+        },
+        FixtureTestConfig {
+            module: Some(false),
+            ..Default::default()
+        },
+    );
+}
+
+#[fixture("tests/fixture/sourcemap/**/code.js")]
+fn jsx_dom_expressions_fixture_sourcemap(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            module_name: "r-dom".to_string(),
+            ..Default::default()
+        },
+        FixtureTestConfig {
+            sourcemap: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[fixture("tests/fixture/dev/**/code.js")]
+fn jsx_dom_expressions_fixture_dev(input: PathBuf) {
+    run_dev_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        built_ins: vec!["For".to_string(), "Show".to_string()],
+        dev: true,
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/hmr/**/code.js")]
+fn jsx_dom_expressions_fixture_hmr(input: PathBuf) {
+    run_dev_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        dev: true,
+        hmr: Some(Hmr::Vite),
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/stable-ids/**/code.js")]
+fn jsx_dom_expressions_fixture_stable_ids(input: PathBuf) {
+    run_dev_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        stable_ids: true,
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/shared-templates/**/code.js")]
+fn jsx_dom_expressions_fixture_shared_templates(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        shared_templates: "virtual:dom-templates".to_string(),
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/minify/**/code.js")]
+fn jsx_dom_expressions_fixture_minify(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        minify_templates: true,
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/helper-imports/**/code.js")]
+fn jsx_dom_expressions_fixture_helper_imports(input: PathBuf) {
+    run_fixture(input, || {
+        serde_json::from_str(
+            r#"{
+                "moduleName": "r-dom",
+                "helperImports": {
+                    "effect": { "moduleName": "r-core", "importName": "createRenderEffect" },
+                    "memo": "r-core",
+                    "createComponent": { "moduleName": "r-core" }
+                }
+            }"#,
+        )
+        .unwrap()
+    });
+}
+
+#[fixture("tests/fixture/non-delegated-events/**/code.js")]
+fn jsx_dom_expressions_fixture_non_delegated_events(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        delegated_events: vec!["scroll".to_string()],
+        non_delegated_events: vec!["click".to_string(), "scroll".to_string()],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/static-bindings/**/code.js")]
+fn jsx_dom_expressions_fixture_static_bindings(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        static_functions: vec!["cn".to_string(), "intl.format".to_string()],
+        static_modules: vec!["./i18n".to_string(), "**/*.module.css".to_string()],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/whitespace/**/code.js")]
+fn jsx_dom_expressions_fixture_whitespace(input: PathBuf) {
+    run_fixture(input, || Config {
+        module_name: "r-dom".to_string(),
+        whitespace_sensitive_tags: vec![
+            "pre".to_string(),
+            "textarea".to_string(),
+            "code".to_string(),
+        ],
+        ..Default::default()
+    });
+}
+
+#[fixture("tests/fixture/pragma/**/code.js")]
+fn jsx_dom_expressions_fixture_pragma(input: PathBuf) {
+    run_fixture_with(
+        input,
+        || Config {
+            module_name: "r-dom".to_string(),
+            ..Default::default()
+        },
+        FixtureTestConfig {
+            allow_error: true,
+            ..Default::default()
+        },
+    );
+}
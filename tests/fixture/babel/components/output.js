@@ -1,12 +1,6 @@
-import { use as _$use } from "r-dom";
-import { template as _$template } from "r-dom";
-import { mergeProps as _$mergeProps } from "r-dom";
-import { memo as _$memo } from "r-dom";
-import { insert as _$insert } from "r-dom";
-import { createComponent as _$createComponent } from "r-dom";
-import { For as _$For } from "r-dom";
-const _tmpl$ = /*#__PURE__*/ _$template(`<div>Hello `), _tmpl$2 = /*#__PURE__*/ _$template(`<div>`), _tmpl$3 = /*#__PURE__*/ _$template(`<div>From Parent`), _tmpl$4 = /*#__PURE__*/ _$template(`<div> | <!> | <!> | <!> | <!> | `), _tmpl$5 = /*#__PURE__*/ _$template(`<div> | <!> | <!> | `), _tmpl$6 = /*#__PURE__*/ _$template(`<div> | <!> |  |  | <!> | `), _tmpl$7 = /*#__PURE__*/ _$template(`<span>1`), _tmpl$8 = /*#__PURE__*/ _$template(`<span>2`), _tmpl$9 = /*#__PURE__*/ _$template(`<span>3`);
+import { For as _$For, createComponent as _$createComponent, insert as _$insert, memo as _$memo, mergeProps as _$mergeProps, template as _$template, use as _$use } from "r-dom";
 import { Show } from "somewhere";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>Hello `), _tmpl$2 = /*#__PURE__*/ _$template(`<div>`), _tmpl$3 = /*#__PURE__*/ _$template(`<div>From Parent`), _tmpl$4 = /*#__PURE__*/ _$template(`<div> | <!> | <!> | <!> | <!> | `), _tmpl$5 = /*#__PURE__*/ _$template(`<div> | <!> | <!> | `), _tmpl$6 = /*#__PURE__*/ _$template(`<div> | <!> |  |  | <!> | `), _tmpl$7 = /*#__PURE__*/ _$template(`<span>1`), _tmpl$8 = /*#__PURE__*/ _$template(`<span>2`), _tmpl$9 = /*#__PURE__*/ _$template(`<span>3`);
 const Child = (props1)=>{
     const [s, set] = createSignal();
     return [
@@ -26,7 +20,7 @@ const Child = (props1)=>{
 };
 export const template = (props1)=>{
     let childRef;
-    const { content  } = props1;
+    const { content } = props1;
     return (()=>{
         const _el$4 = _tmpl$2();
         _$insert(_el$4, _$createComponent(Child, _$mergeProps({
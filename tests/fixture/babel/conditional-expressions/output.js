@@ -1,8 +1,4 @@
-import { template as _$template } from "r-dom";
-import { memo as _$memo } from "r-dom";
-import { insert as _$insert } from "r-dom";
-import { effect as _$effect } from "r-dom";
-import { createComponent as _$createComponent } from "r-dom";
+import { createComponent as _$createComponent, effect as _$effect, insert as _$insert, memo as _$memo, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<div>`);
 export const template1 = (()=>{
     const _el$ = _tmpl$();
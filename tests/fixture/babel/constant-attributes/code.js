@@ -0,0 +1,24 @@
+const BASE = "docs";
+const RED = "red";
+const SIZE = 12;
+const BUTTON_CLASS = "btn btn-primary";
+const CARD_STYLE = { color: RED, "font-size": `${SIZE}px` };
+const DISABLED = true;
+const theme = { accent: "blue" };
+
+export const link = <a href={`/${BASE}/x`} class={BUTTON_CLASS}>Docs</a>;
+export const styled = <div style={{ color: RED, "font-size": `${SIZE}px`, width: SIZE * 2 + "px" }} />;
+export const constStyle = <div style={CARD_STYLE} />;
+export const memberStyle = <div style={{ color: theme.accent }} classList={{ active: DISABLED }} />;
+export const booleanAttr = <button disabled={DISABLED} tabIndex={SIZE - 12}>Go</button>;
+export const mixed = <div style={{ color: RED, height: height() }} title={theme.missing} />;
+const CLASSES = { active: DISABLED, hidden: !DISABLED };
+export const constClassList = <div classList={CLASSES} />;
+const HIDDEN = false;
+export const falseBoolean = <input hidden={HIDDEN} readonly={!HIDDEN} required={true} />;
+
+const MUTABLE_STYLE = { color: RED };
+MUTABLE_STYLE.color = "blue";
+const MUTABLE_CLASSES = { active: true };
+MUTABLE_CLASSES["active"] = false;
+export const mutatedConstStyle = <div style={MUTABLE_STYLE} classList={MUTABLE_CLASSES} />;
@@ -0,0 +1,64 @@
+import { classList as _$classList, effect as _$effect, setAttribute as _$setAttribute, style as _$style, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<a href="/docs/x" class="btn btn-primary">Docs`), _tmpl$2 = /*#__PURE__*/ _$template(`<div style="color:red;font-size:12px;width:24px">`), _tmpl$3 = /*#__PURE__*/ _$template(`<div style="color:red;font-size:12px">`), _tmpl$4 = /*#__PURE__*/ _$template(`<div style="color:blue" class="active">`), _tmpl$5 = /*#__PURE__*/ _$template(`<button disabled tabindex="0">Go`), _tmpl$6 = /*#__PURE__*/ _$template(`<div style="color:red">`), _tmpl$7 = /*#__PURE__*/ _$template(`<div class="active">`), _tmpl$8 = /*#__PURE__*/ _$template(`<input readonly>`), _tmpl$9 = /*#__PURE__*/ _$template(`<div>`);
+const BASE = "docs";
+const RED = "red";
+const SIZE = 12;
+const BUTTON_CLASS = "btn btn-primary";
+const CARD_STYLE = {
+    color: RED,
+    "font-size": `${SIZE}px`
+};
+const DISABLED = true;
+const theme = {
+    accent: "blue"
+};
+export const link = _tmpl$();
+export const styled = _tmpl$2();
+export const constStyle = _tmpl$3();
+export const memberStyle = _tmpl$4();
+export const booleanAttr = _tmpl$5();
+export const mixed = (()=>{
+    const _el$6 = _tmpl$6();
+    _$effect((_p$)=>{
+        const _v$ = height(), _v$2 = theme.missing;
+        _v$ !== _p$._v$ && ((_p$._v$ = _v$) != null ? _el$6.style.setProperty("height", _v$) : _el$6.style.removeProperty("height"));
+        _v$2 !== _p$._v$2 && _$setAttribute(_el$6, "title", _p$._v$2 = _v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$6;
+})();
+const CLASSES = {
+    active: DISABLED,
+    hidden: !DISABLED
+};
+export const constClassList = _tmpl$7();
+const HIDDEN = false;
+export const falseBoolean = (()=>{
+    const _el$8 = _tmpl$8();
+    _el$8.required = true;
+    return _el$8;
+})();
+const MUTABLE_STYLE = {
+    color: RED
+};
+MUTABLE_STYLE.color = "blue";
+const MUTABLE_CLASSES = {
+    active: true
+};
+MUTABLE_CLASSES["active"] = false;
+export const mutatedConstStyle = (()=>{
+    const _el$9 = _tmpl$9();
+    _$effect((_p$)=>{
+        const _v$3 = MUTABLE_STYLE, _v$4 = MUTABLE_CLASSES;
+        _p$._v$3 = _$style(_el$9, _v$3, _p$._v$3);
+        _p$._v$4 = _$classList(_el$9, _v$4, _p$._v$4);
+        return _p$;
+    }, {
+        _v$3: undefined,
+        _v$4: undefined
+    });
+    return _el$9;
+})();
@@ -0,0 +1,62 @@
+const theme = { color: "red", size: 2 };
+const prefix = `btn-${theme.color}`;
+let label = "Save";
+let count = 1;
+count++;
+let name = "a";
+name = "b";
+var unset;
+
+export const folded = <button class={prefix + " large"}>{label}</button>;
+export const member = <div title={theme.color}>{theme.size * 3}</div>;
+export const conditional = <span>{theme.size > 1 ? "big" : "small"}{undefined ?? "fallback"}</span>;
+export const updated = <span>{count}</span>;
+export const assigned = <span>{name}</span>;
+export const uninitialized = <span>{unset}</span>;
+
+const INFINITE = 1 / 0;
+const NEGATIVE_ZERO = -0;
+const HUGE = 1e21;
+const TINY = 0.0000001;
+const MILLION = 1000 * 1000;
+export const numbers = <span>{INFINITE} {-INFINITE} {0 / 0} {NEGATIVE_ZERO} {HUGE} {TINY} {MILLION} {0.1 + 0.2}</span>;
+export const numberAttr = <div data-inf={INFINITE} data-huge={HUGE} />;
+
+export const sequence = <div>{(log(), "x")}</div>;
+export const pureSequence = <div>{("a", "b")}</div>;
+
+const mutable = { accent: "blue" };
+mutable.accent = "red";
+export const mutatedMember = <div class={mutable.accent}>{mutable.accent}</div>;
+
+const nested = { colors: { accent: "blue" } };
+nested.colors.accent = "red";
+const deleted = { accent: "blue" };
+delete deleted.accent;
+export const mutatedStyle = <div style={deleted} classList={nested} />;
+
+export const beforeRedeclare = <span>{v}</span>;
+var v = "a";
+var v = "b";
+
+export const readEarly = <span>{early}</span>;
+var early = "a";
+
+const FIRST = "x";
+let key = FIRST;
+for (key of list);
+export const loopKey = <span>{key}</span>;
+
+const assigned = { accent: "blue" };
+Object.assign(assigned, overrides);
+export const assignedMember = <div class={assigned.accent} style={assigned} />;
+
+const spread = { color: "red", ...overrides };
+export const spreadMember = <div title={spread.color} style={spread} />;
+
+const repeated = { color: "red", color: "blue" };
+export const repeatedMember = <div title={repeated.color} style={repeated} />;
+
+const methodObject = { accent: "blue" };
+methodObject.reset();
+export const methodMember = <div title={methodObject.accent} />;
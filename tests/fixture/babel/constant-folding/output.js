@@ -0,0 +1,150 @@
+import { classList as _$classList, className as _$className, effect as _$effect, insert as _$insert, memo as _$memo, setAttribute as _$setAttribute, style as _$style, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button class="btn-red large">Save`), _tmpl$2 = /*#__PURE__*/ _$template(`<div title="red">6`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>fallback`), _tmpl$4 = /*#__PURE__*/ _$template(`<span>`), _tmpl$5 = /*#__PURE__*/ _$template(`<span>Infinity -Infinity NaN 0 1e+21 1e-7 1000000 0.30000000000000004`), _tmpl$6 = /*#__PURE__*/ _$template(`<div data-inf="Infinity" data-huge="1e+21">`), _tmpl$7 = /*#__PURE__*/ _$template(`<div>`), _tmpl$8 = /*#__PURE__*/ _$template(`<div>b`), _tmpl$9 = /*#__PURE__*/ _$template(`<div title="blue" style="color:blue">`);
+const theme = {
+    color: "red",
+    size: 2
+};
+const prefix = `btn-${theme.color}`;
+let label = "Save";
+let count = 1;
+count++;
+let name = "a";
+name = "b";
+var unset;
+export const folded = _tmpl$();
+export const member = _tmpl$2();
+export const conditional = (()=>{
+    const _el$3 = _tmpl$3(), _el$4 = _el$3.firstChild;
+    _$insert(_el$3, ()=>theme.size > 1 ? "big" : "small", _el$4);
+    return _el$3;
+})();
+export const updated = (()=>{
+    const _el$5 = _tmpl$4();
+    _$insert(_el$5, count);
+    return _el$5;
+})();
+export const assigned = (()=>{
+    const _el$6 = _tmpl$4();
+    _$insert(_el$6, name);
+    return _el$6;
+})();
+export const uninitialized = (()=>{
+    const _el$7 = _tmpl$4();
+    _$insert(_el$7, unset);
+    return _el$7;
+})();
+const INFINITE = 1 / 0;
+const NEGATIVE_ZERO = -0;
+const HUGE = 1e21;
+const TINY = 0.0000001;
+const MILLION = 1000 * 1000;
+export const numbers = _tmpl$5();
+export const numberAttr = _tmpl$6();
+export const sequence = (()=>{
+    const _el$10 = _tmpl$7();
+    _$insert(_el$10, ()=>(log(), "x"));
+    return _el$10;
+})();
+export const pureSequence = _tmpl$8();
+const mutable = {
+    accent: "blue"
+};
+mutable.accent = "red";
+export const mutatedMember = (()=>{
+    const _el$12 = _tmpl$7();
+    _$insert(_el$12, ()=>mutable.accent);
+    _$effect(()=>_$className(_el$12, mutable.accent));
+    return _el$12;
+})();
+const nested = {
+    colors: {
+        accent: "blue"
+    }
+};
+nested.colors.accent = "red";
+const deleted = {
+    accent: "blue"
+};
+delete deleted.accent;
+export const mutatedStyle = (()=>{
+    const _el$13 = _tmpl$7();
+    _$effect((_p$)=>{
+        const _v$ = deleted, _v$2 = nested;
+        _p$._v$ = _$style(_el$13, _v$, _p$._v$);
+        _p$._v$2 = _$classList(_el$13, _v$2, _p$._v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$13;
+})();
+export const beforeRedeclare = (()=>{
+    const _el$14 = _tmpl$4();
+    _$insert(_el$14, v);
+    return _el$14;
+})();
+var v = "a";
+var v = "b";
+export const readEarly = (()=>{
+    const _el$15 = _tmpl$4();
+    _$insert(_el$15, early);
+    return _el$15;
+})();
+var early = "a";
+const FIRST = "x";
+let key = FIRST;
+for (key of list);
+export const loopKey = (()=>{
+    const _el$16 = _tmpl$4();
+    _$insert(_el$16, key);
+    return _el$16;
+})();
+const assigned = {
+    accent: "blue"
+};
+Object.assign(assigned, overrides);
+export const assignedMember = (()=>{
+    const _el$17 = _tmpl$7();
+    _$effect((_p$)=>{
+        const _v$3 = assigned.accent, _v$4 = assigned;
+        _v$3 !== _p$._v$3 && _$className(_el$17, _p$._v$3 = _v$3);
+        _p$._v$4 = _$style(_el$17, _v$4, _p$._v$4);
+        return _p$;
+    }, {
+        _v$3: undefined,
+        _v$4: undefined
+    });
+    return _el$17;
+})();
+const spread = {
+    color: "red",
+    ...overrides
+};
+export const spreadMember = (()=>{
+    const _el$18 = _tmpl$7();
+    _$effect((_p$)=>{
+        const _v$5 = spread.color, _v$6 = spread;
+        _v$5 !== _p$._v$5 && _$setAttribute(_el$18, "title", _p$._v$5 = _v$5);
+        _p$._v$6 = _$style(_el$18, _v$6, _p$._v$6);
+        return _p$;
+    }, {
+        _v$5: undefined,
+        _v$6: undefined
+    });
+    return _el$18;
+})();
+const repeated = {
+    color: "red",
+    color: "blue"
+};
+export const repeatedMember = _tmpl$9();
+const methodObject = {
+    accent: "blue"
+};
+methodObject.reset();
+export const methodMember = (()=>{
+    const _el$20 = _tmpl$7();
+    _$effect(()=>_$setAttribute(_el$20, "title", methodObject.accent));
+    return _el$20;
+})();
@@ -1,7 +1,4 @@
-import { template as _$template } from "r-dom";
-import { setAttribute as _$setAttribute } from "r-dom";
-import { getOwner as _$getOwner } from "r-dom";
-import { effect as _$effect } from "r-dom";
+import { effect as _$effect, getOwner as _$getOwner, setAttribute as _$setAttribute, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<my-element>`, true, false), _tmpl$2 = /*#__PURE__*/ _$template(`<my-element><header slot="head">Title`, true, false), _tmpl$3 = /*#__PURE__*/ _$template(`<slot name="head">`);
 export const template = (()=>{
     const _el$ = _tmpl$();
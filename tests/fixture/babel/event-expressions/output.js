@@ -1,6 +1,4 @@
-import { template as _$template } from "r-dom";
-import { delegateEvents as _$delegateEvents } from "r-dom";
-import { addEventListener as _$addEventListener } from "r-dom";
+import { addEventListener as _$addEventListener, delegateEvents as _$delegateEvents, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Change Bound</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Delegated</button><button>Click Listener</button><button>Click Capture`);
 function hoisted1() {
     console.log("hoisted");
@@ -0,0 +1,8 @@
+import { insert, render } from "r-dom";
+import { createSignal } from "solid-js";
+
+const [count, setCount] = createSignal(0);
+
+export const template = <div>{count()}</div>;
+
+render(() => template, document.body);
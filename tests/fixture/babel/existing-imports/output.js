@@ -0,0 +1,10 @@
+import { insert, render, template as _$template } from "r-dom";
+import { createSignal } from "solid-js";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>`);
+const [count, setCount] = createSignal(0);
+export const template = (()=>{
+    const _el$ = _tmpl$();
+    insert(_el$, count);
+    return _el$;
+})();
+render(()=>template, document.body);
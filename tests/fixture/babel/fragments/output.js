@@ -1,8 +1,4 @@
-import { template as _$template } from "r-dom";
-import { setAttribute as _$setAttribute } from "r-dom";
-import { memo as _$memo } from "r-dom";
-import { effect as _$effect } from "r-dom";
-import { createComponent as _$createComponent } from "r-dom";
+import { createComponent as _$createComponent, effect as _$effect, memo as _$memo, setAttribute as _$setAttribute, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<div>First`), _tmpl$2 = /*#__PURE__*/ _$template(`<div>Last`), _tmpl$3 = /*#__PURE__*/ _$template(`<div>`), _tmpl$4 = /*#__PURE__*/ _$template(`<span>1`), _tmpl$5 = /*#__PURE__*/ _$template(`<span>2`), _tmpl$6 = /*#__PURE__*/ _$template(`<span>3`);
 export const multiStatic = [
     _tmpl$(),
@@ -1,8 +1,4 @@
-import { template as _$template } from "r-dom";
-import { spread as _$spread } from "r-dom";
-import { mergeProps as _$mergeProps } from "r-dom";
-import { insert as _$insert } from "r-dom";
-import { createComponent as _$createComponent } from "r-dom";
+import { createComponent as _$createComponent, insert as _$insert, mergeProps as _$mergeProps, spread as _$spread, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<div>`), _tmpl$2 = /*#__PURE__*/ _$template(`<module>`), _tmpl$3 = /*#__PURE__*/ _$template(`<module>Hello`), _tmpl$4 = /*#__PURE__*/ _$template(`<module>Hi `), _tmpl$5 = /*#__PURE__*/ _$template(`<module>Hi`), _tmpl$6 = /*#__PURE__*/ _$template(`<div>Test 1`);
 const children = _tmpl$();
 const dynamic = {
@@ -1,5 +1,4 @@
-import { template as _$template } from "r-dom";
-import { createComponent as _$createComponent } from "r-dom";
+import { createComponent as _$createComponent, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(`<namespace:tag>`);
 export const template = _$createComponent(module.A, {});
 export const template2 = _$createComponent(module.a.B, {});
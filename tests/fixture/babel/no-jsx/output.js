@@ -0,0 +1,8 @@
+import { createSignal } from "r-dom";
+export function counter() {
+    const [count, setCount] = createSignal(0);
+    return {
+        count,
+        increment: ()=>setCount(count() + 1)
+    };
+}
@@ -0,0 +1,26 @@
+const accent = "red";
+
+export const staticStyle = (
+  <style>
+    a &gt; b {"{"} content: "&amp;"; {"}"}
+    .card:not(.active) {"{"} color: {accent}; {"}"}
+  </style>
+);
+
+export const dynamicStyle = <style>{css()}</style>;
+
+export const mixedStyle = (
+  <style>
+    .theme {"{"} color: {color()}; background: `url(${image})`; {"}"}
+  </style>
+);
+
+export const staticScript = (
+  <script type="module">
+    if (a &lt; b &amp;&amp; c) run()
+    // keep lines apart
+    done()
+  </script>
+);
+
+export const dynamicScript = <script type="application/json">{JSON.stringify(data)}</script>;
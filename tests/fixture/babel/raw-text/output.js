@@ -0,0 +1,29 @@
+import { effect as _$effect, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<style>
+    a > b { content: "&"; }
+    .card:not(.active) { color: red; }
+  `), _tmpl$2 = /*#__PURE__*/ _$template(`<style> `), _tmpl$3 = /*#__PURE__*/ _$template(`<script type="module">
+    if (a < b && c) run()
+    // keep lines apart
+    done()
+  `), _tmpl$4 = /*#__PURE__*/ _$template(`<script type="application/json"> `);
+const accent = "red";
+export const staticStyle = _tmpl$();
+export const dynamicStyle = (()=>{
+    const _el$2 = _tmpl$2(), _el$3 = _el$2.firstChild;
+    _$effect(()=>_el$3.data = css());
+    return _el$2;
+})();
+export const mixedStyle = (()=>{
+    const _el$4 = _tmpl$2(), _el$5 = _el$4.firstChild;
+    _$effect(()=>_el$5.data = `
+    .theme { color: ${color()}; background: \`url($${image})\`; }
+  `);
+    return _el$4;
+})();
+export const staticScript = _tmpl$3();
+export const dynamicScript = (()=>{
+    const _el$7 = _tmpl$4(), _el$8 = _el$7.firstChild;
+    _$effect(()=>_el$8.data = JSON.stringify(data));
+    return _el$7;
+})();
@@ -1,5 +1,4 @@
-import { template as _$template } from "r-dom";
-import { memo as _$memo } from "r-dom";
+import { memo as _$memo, template as _$template } from "r-dom";
 const _tmpl$ = /*#__PURE__*/ _$template(
     `<div id="main"><style>div { color: red; }</style><h1>Welcome</h1><label for="entry">Edit:</label><input id="entry" type="text">`
   ),
@@ -0,0 +1,13 @@
+import { Greeting, Layout } from "./components";
+
+export const App = () => (
+  <Layout.Main title="Home">
+    <div class="content">
+      <Greeting name={user.name} />
+      <span>{user.count}</span>
+    </div>
+    <Show when={user.admin}>
+      <button>Admin</button>
+    </Show>
+  </Layout.Main>
+);
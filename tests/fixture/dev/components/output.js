@@ -0,0 +1,37 @@
+import { Show as _$Show, createComponent as _$createComponent, insert as _$insert, template as _$template } from "r-dom";
+import { Greeting, Layout } from "./components";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="content" data-source-loc="tests/fixture/dev/components/code.js:5:5"><span data-source-loc="tests/fixture/dev/components/code.js:7:7">`), _tmpl$2 = /*#__PURE__*/ _$template(`<button data-source-loc="tests/fixture/dev/components/code.js:10:7">Admin`);
+export const App = ()=>_$createComponent(Layout.Main, {
+        title: "Home",
+        get children () {
+            return [
+                (()=>{
+                    const _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+                    _$insert(_el$, _$createComponent(Greeting, {
+                        get name () {
+                            return user.name;
+                        }
+                    }, {
+                        name: "Greeting",
+                        location: "tests/fixture/dev/components/code.js:6:7"
+                    }), _el$2);
+                    _$insert(_el$2, ()=>user.count);
+                    return _el$;
+                })(),
+                _$createComponent(_$Show, {
+                    get when () {
+                        return user.admin;
+                    },
+                    get children () {
+                        return _tmpl$2();
+                    }
+                }, {
+                    name: "Show",
+                    location: "tests/fixture/dev/components/code.js:9:5"
+                })
+            ];
+        }
+    }, {
+        name: "Layout.Main",
+        location: "tests/fixture/dev/components/code.js:4:3"
+    });
@@ -0,0 +1,18 @@
+export const scene = (
+  <mesh position={state.position}>
+    <text>{state.label}</text>
+  </mesh>
+);
+
+export const overlay = (
+  <panel>
+    <label>{state.title}</label>
+  </panel>
+);
+
+export const page = (
+  <div class="page">
+    <mesh visible />
+    <panel />
+  </div>
+);
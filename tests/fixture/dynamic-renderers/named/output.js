@@ -0,0 +1,27 @@
+import { createElement as _$createElement, effect as _$effect, insert as _$insert, insertNode as _$insertNode, setProp as _$setProp } from "my-canvas";
+import { createElement as _$createElement1, insert as _$insert1, insertNode as _$insertNode1 } from "my-hud";
+import { insert as _$insert2, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="page">`);
+export const scene = (()=>{
+    const _el$ = _$createElement("mesh"), _el$2 = _$createElement("text");
+    _$insertNode(_el$, _el$2);
+    _$insert(_el$2, ()=>state.label);
+    _$effect((_$p)=>_$setProp(_el$, "position", state.position, _$p));
+    return _el$;
+})();
+export const overlay = (()=>{
+    const _el$3 = _$createElement1("panel"), _el$4 = _$createElement1("label");
+    _$insertNode1(_el$3, _el$4);
+    _$insert1(_el$4, ()=>state.title);
+    return _el$3;
+})();
+export const page = (()=>{
+    const _el$5 = _tmpl$();
+    _$insert2(_el$5, (()=>{
+        const _el$6 = _$createElement("mesh");
+        _$setProp(_el$6, "visible", true);
+        return _el$6;
+    })(), null);
+    _$insert2(_el$5, _$createElement1("panel"), null);
+    return _el$5;
+})();
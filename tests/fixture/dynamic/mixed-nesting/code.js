@@ -0,0 +1,21 @@
+export const domParent = (
+  <div>
+    <mesh position={p()} />
+    <span>{x()}</span>
+  </div>
+);
+
+export const universalParent = (
+  <mesh>
+    <div class="x">{y()}</div>
+    <group visible />
+  </mesh>
+);
+
+export const deep = (
+  <div>
+    <mesh>
+      <span>{z()}</span>
+    </mesh>
+  </div>
+);
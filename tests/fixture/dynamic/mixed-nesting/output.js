@@ -0,0 +1,37 @@
+import { createElement as _$createElement, effect as _$effect, insert as _$insert, insertNode as _$insertNode, setProp as _$setProp } from "r-custom";
+import { insert as _$insert1, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><span>`), _tmpl$2 = /*#__PURE__*/ _$template(`<div class="x">`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>`), _tmpl$4 = /*#__PURE__*/ _$template(`<div>`);
+export const domParent = (()=>{
+    const _el$ = _tmpl$(), _el$3 = _el$.firstChild;
+    _$insert1(_el$, (()=>{
+        const _el$2 = _$createElement("mesh");
+        _$effect((_$p)=>_$setProp(_el$2, "position", p(), _$p));
+        return _el$2;
+    })(), _el$3);
+    _$insert1(_el$3, x);
+    return _el$;
+})();
+export const universalParent = (()=>{
+    const _el$4 = _$createElement("mesh"), _el$6 = _$createElement("group");
+    _$insertNode(_el$4, _el$6);
+    _$insert(_el$4, (()=>{
+        const _el$5 = _tmpl$2();
+        _$insert1(_el$5, y);
+        return _el$5;
+    })(), _el$6);
+    _$setProp(_el$6, "visible", true);
+    return _el$4;
+})();
+export const deep = (()=>{
+    const _el$7 = _tmpl$4();
+    _$insert1(_el$7, (()=>{
+        const _el$8 = _$createElement("mesh");
+        _$insert(_el$8, (()=>{
+            const _el$9 = _tmpl$3();
+            _$insert1(_el$9, z);
+            return _el$9;
+        })());
+        return _el$8;
+    })());
+    return _el$7;
+})();
@@ -0,0 +1,14 @@
+export const page = (
+  <div class="page">
+    <span>{state.title}</span>
+    <button onClick={increment}>+</button>
+  </div>
+);
+
+export const scene = (
+  <mesh position={state.position} visible>
+    <text>{state.label}</text>
+  </mesh>
+);
+
+export const app = <Canvas>{page}</Canvas>;
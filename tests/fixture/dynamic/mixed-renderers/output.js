@@ -0,0 +1,23 @@
+import { createElement as _$createElement, effect as _$effect, insert as _$insert, insertNode as _$insertNode, setProp as _$setProp } from "r-custom";
+import { addEventListener as _$addEventListener, createComponent as _$createComponent, delegateEvents as _$delegateEvents, insert as _$insert1, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="page"><span></span><button>+`);
+export const page = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+    _$insert1(_el$2, ()=>state.title);
+    _$addEventListener(_el$3, "click", increment, true);
+    return _el$;
+})();
+export const scene = (()=>{
+    const _el$4 = _$createElement("mesh"), _el$5 = _$createElement("text");
+    _$insertNode(_el$4, _el$5);
+    _$setProp(_el$4, "visible", true);
+    _$insert(_el$5, ()=>state.label);
+    _$effect((_$p)=>_$setProp(_el$4, "position", state.position, _$p));
+    return _el$4;
+})();
+export const app = _$createComponent(Canvas, {
+    children: page
+});
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,3 @@
+export const template = <Foo:Bar value={1} />;
+
+export const template2 = <Foo.Bar value={1} />;
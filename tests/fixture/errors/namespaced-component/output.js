@@ -0,0 +1,7 @@
+import { createComponent as _$createComponent } from "r-dom";
+export const template = _$createComponent("Foo:Bar", {
+    value: 1
+});
+export const template2 = _$createComponent(Foo.Bar, {
+    value: 1
+});
@@ -0,0 +1,10 @@
+export const template = (
+  <div>
+    <>
+      <span>Inside fragment</span>
+    </>
+    <span>After</span>
+  </div>
+);
+
+export const template2 = <p>Still compiled</p>;
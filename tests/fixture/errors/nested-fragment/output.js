@@ -0,0 +1,4 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><span>After`), _tmpl$2 = /*#__PURE__*/ _$template(`<p>Still compiled`);
+export const template = _tmpl$();
+export const template2 = _tmpl$2();
@@ -0,0 +1,7 @@
+export const template = <button onClick={[, data]}>Hole</button>;
+
+export const template2 = <button onMouseMove={[handler, ...rest]}>Spread</button>;
+
+export const template3 = <div style={{ 1: "a", [key]: "b", ...rest, color: "red" }} />;
+
+export const template4 = <div title=<span>Label</span> />;
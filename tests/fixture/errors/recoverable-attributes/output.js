@@ -0,0 +1,37 @@
+import { addEventListener as _$addEventListener, delegateEvents as _$delegateEvents, effect as _$effect, setAttribute as _$setAttribute, style as _$style, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button>Hole`), _tmpl$2 = /*#__PURE__*/ _$template(`<button>Spread`), _tmpl$3 = /*#__PURE__*/ _$template(`<div>`), _tmpl$4 = /*#__PURE__*/ _$template(`<span>Label`);
+export const template = (()=>{
+    const _el$ = _tmpl$();
+    _$addEventListener(_el$, "click", [
+        ,
+        data
+    ], true);
+    return _el$;
+})();
+export const template2 = (()=>{
+    const _el$2 = _tmpl$2();
+    _$addEventListener(_el$2, "mousemove", [
+        handler,
+        ...rest
+    ], true);
+    return _el$2;
+})();
+export const template3 = (()=>{
+    const _el$3 = _tmpl$3();
+    _$effect((_$p)=>_$style(_el$3, {
+            1: "a",
+            [key]: "b",
+            ...rest,
+            color: "red"
+        }, _$p));
+    return _el$3;
+})();
+export const template4 = (()=>{
+    const _el$4 = _tmpl$3();
+    _$setAttribute(_el$4, "title", _tmpl$4());
+    return _el$4;
+})();
+_$delegateEvents([
+    "click",
+    "mousemove"
+]);
@@ -0,0 +1,7 @@
+import { Child } from "./child";
+
+export const view = (
+  <div class={state.active ? "on" : "off"} title={state.title}>
+    {state.ready ? <span>Ready</span> : <Child />}
+  </div>
+);
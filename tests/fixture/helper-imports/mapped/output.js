@@ -0,0 +1,21 @@
+import { createComponent as _$createComponent, createRenderEffect as _$createRenderEffect, memo as _$memo } from "r-core";
+import { className as _$className, insert as _$insert, setAttribute as _$setAttribute, template as _$template } from "r-dom";
+import { Child } from "./child";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>`), _tmpl$2 = /*#__PURE__*/ _$template(`<span>Ready`);
+export const view = (()=>{
+    const _el$ = _tmpl$();
+    _$insert(_el$, (()=>{
+        const _c$ = _$memo(()=>!!state.ready);
+        return ()=>_c$() ? _tmpl$2() : _$createComponent(Child, {});
+    })());
+    _$createRenderEffect((_p$)=>{
+        const _v$ = state.active ? "on" : "off", _v$2 = state.title;
+        _v$ !== _p$._v$ && _$className(_el$, _p$._v$ = _v$);
+        _v$2 !== _p$._v$2 && _$setAttribute(_el$, "title", _p$._v$2 = _v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$;
+})();
@@ -0,0 +1,27 @@
+import { createSignal } from "r-dom";
+
+// used before its declaration, which function hoisting allows
+export const early = <Footer />;
+
+function Counter(props) {
+  const [count, setCount] = createSignal(props.initial);
+  return <button onClick={() => setCount(count() + 1)}>{count()}</button>;
+}
+
+export const Header = (props) => <h1>{props.title}</h1>;
+
+export function Footer() {
+  return <footer>Bye</footer>;
+}
+
+const helper = () => <span>not a component</span>;
+
+export default function App() {
+  return (
+    <main>
+      <Header title="Counter" />
+      <Counter initial={1} />
+      <Footer />
+    </main>
+  );
+}
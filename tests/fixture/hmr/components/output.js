@@ -0,0 +1,67 @@
+import { $$component as _$$$component, $$refresh as _$$$refresh, $$registry as _$$$registry } from "solid-refresh";
+import { createSignal, createComponent as _$createComponent, delegateEvents as _$delegateEvents, insert as _$insert, template as _$template } from "r-dom";
+const _REGISTRY = _$$$registry();
+const _tmpl$ = /*#__PURE__*/ _$template(`<button data-source-loc="tests/fixture/hmr/components/code.js:8:10">`), _tmpl$2 = /*#__PURE__*/ _$template(`<h1 data-source-loc="tests/fixture/hmr/components/code.js:11:34">`), _tmpl$3 = /*#__PURE__*/ _$template(`<footer data-source-loc="tests/fixture/hmr/components/code.js:14:10">Bye`), _tmpl$4 = /*#__PURE__*/ _$template(`<span data-source-loc="tests/fixture/hmr/components/code.js:17:22">not a component`), _tmpl$5 = /*#__PURE__*/ _$template(`<main data-source-loc="tests/fixture/hmr/components/code.js:21:5">`);
+// used before its declaration, which function hoisting allows
+export const early = _$createComponent(Footer, {}, {
+    name: "Footer",
+    location: "tests/fixture/hmr/components/code.js:4:22"
+});
+function Counter(props) {
+    const [count, setCount] = createSignal(props.initial);
+    return (()=>{
+        const _el$ = _tmpl$();
+        _el$.$$click = ()=>setCount(count() + 1);
+        _$insert(_el$, count);
+        return _el$;
+    })();
+}
+Counter = _$$$component(_REGISTRY, "Counter", Counter, {
+    location: "tests/fixture/hmr/components/code.js:6:1"
+});
+export const Header = _$$$component(_REGISTRY, "Header", (props)=>(()=>{
+        const _el$2 = _tmpl$2();
+        _$insert(_el$2, ()=>props.title);
+        return _el$2;
+    })(), {
+    location: "tests/fixture/hmr/components/code.js:11:14"
+});
+export function Footer() {
+    return _tmpl$3();
+}
+Footer = _$$$component(_REGISTRY, "Footer", Footer, {
+    location: "tests/fixture/hmr/components/code.js:13:8"
+});
+const helper = ()=>_tmpl$4();
+export default function App() {
+    return (()=>{
+        const _el$5 = _tmpl$5();
+        _$insert(_el$5, _$createComponent(Header, {
+            title: "Counter"
+        }, {
+            name: "Header",
+            location: "tests/fixture/hmr/components/code.js:22:7"
+        }), null);
+        _$insert(_el$5, _$createComponent(Counter, {
+            initial: 1
+        }, {
+            name: "Counter",
+            location: "tests/fixture/hmr/components/code.js:23:7"
+        }), null);
+        _$insert(_el$5, _$createComponent(Footer, {}, {
+            name: "Footer",
+            location: "tests/fixture/hmr/components/code.js:24:7"
+        }), null);
+        return _el$5;
+    })();
+}
+App = _$$$component(_REGISTRY, "App", App, {
+    location: "tests/fixture/hmr/components/code.js:19:16"
+});
+_$delegateEvents([
+    "click"
+]);
+if (import.meta.hot) {
+    _$$$refresh("vite", import.meta.hot, _REGISTRY);
+    import.meta.hot.accept();
+}
@@ -0,0 +1 @@
+export const view = () => <div>static</div>;
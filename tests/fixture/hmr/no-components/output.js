@@ -0,0 +1,3 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div data-source-loc="tests/fixture/hmr/no-components/code.js:1:27">static`);
+export const view = ()=>_tmpl$();
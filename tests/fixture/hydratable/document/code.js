@@ -0,0 +1,16 @@
+export const template = (
+  <html lang="en">
+    <head>
+      <title>Hello</title>
+    </head>
+    <body>
+      <div>{content()}</div>
+    </body>
+  </html>
+);
+
+export const template2 = (
+  <head>
+    <title>Isolated</title>
+  </head>
+);
@@ -0,0 +1,12 @@
+import { NoHydration as _$NoHydration, createComponent as _$createComponent, getNextElement as _$getNextElement, getNextMatch as _$getNextMatch, insert as _$insert } from "r-dom";
+export const template = (()=>{
+    const _el$ = _$getNextElement(), _el$3 = _$getNextMatch(_el$.firstChild, "body"), _el$4 = _el$3.firstChild;
+    _$createComponent(_$NoHydration, {});
+    _$insert(_el$4, content);
+    return _el$;
+})();
+export const template2 = (()=>{
+    const _el$5 = _$getNextElement();
+    _$createComponent(_$NoHydration, {});
+    return _el$5;
+})();
@@ -0,0 +1,21 @@
+export const template = (
+  <div id="main">
+    <h1>Welcome</h1>
+    {greeting()}
+  </div>
+);
+
+export const template2 = (
+  <div>
+    Hello {name()}, you have {count()} messages
+    <button onClick={() => setCount(0)}>Clear</button>
+  </div>
+);
+
+export const template3 = <span>{single()}</span>;
+
+export const template4 = (
+  <ul>
+    <For each={list()}>{item => <li onClick={select}>{item}</li>}</For>
+  </ul>
+);
@@ -0,0 +1,39 @@
+import { For as _$For, addEventListener as _$addEventListener, createComponent as _$createComponent, delegateEvents as _$delegateEvents, getNextElement as _$getNextElement, getNextMarker as _$getNextMarker, insert as _$insert, runHydrationEvents as _$runHydrationEvents, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><h1>Welcome</h1><!$><!/>`), _tmpl$2 = /*#__PURE__*/ _$template(`<div>Hello <!$><!/>, you have <!$><!/> messages<button>Clear`), _tmpl$3 = /*#__PURE__*/ _$template(`<span>`), _tmpl$4 = /*#__PURE__*/ _$template(`<ul>`), _tmpl$5 = /*#__PURE__*/ _$template(`<li>`);
+export const template = (()=>{
+    const _el$ = _$getNextElement(_tmpl$), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, [_el$4, _co$] = _$getNextMarker(_el$3.nextSibling);
+    _$insert(_el$, greeting, _el$4, _co$);
+    return _el$;
+})();
+export const template2 = (()=>{
+    const _el$5 = _$getNextElement(_tmpl$2), _el$6 = _el$5.firstChild, _el$10 = _el$6.nextSibling, [_el$11, _co$2] = _$getNextMarker(_el$10.nextSibling), _el$7 = _el$11.nextSibling, _el$12 = _el$7.nextSibling, [_el$13, _co$3] = _$getNextMarker(_el$12.nextSibling), _el$8 = _el$13.nextSibling, _el$9 = _el$8.nextSibling;
+    _$insert(_el$5, name, _el$11, _co$2);
+    _$insert(_el$5, count, _el$13, _co$3);
+    _el$9.$$click = ()=>setCount(0);
+    _$runHydrationEvents();
+    return _el$5;
+})();
+export const template3 = (()=>{
+    const _el$14 = _$getNextElement(_tmpl$3);
+    _$insert(_el$14, single);
+    return _el$14;
+})();
+export const template4 = (()=>{
+    const _el$15 = _$getNextElement(_tmpl$4);
+    _$insert(_el$15, _$createComponent(_$For, {
+        get each () {
+            return list();
+        },
+        children: (item)=>(()=>{
+                const _el$16 = _$getNextElement(_tmpl$5);
+                _$addEventListener(_el$16, "click", select, true);
+                _$insert(_el$16, item);
+                _$runHydrationEvents();
+                return _el$16;
+            })()
+    }));
+    return _el$15;
+})();
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,4 @@
+/** @jsxImportSource r-dom */
+import { state } from "./store";
+
+export const template = <div class="greeting">Hello {state.name}</div>;
@@ -0,0 +1,8 @@
+import { insert as _$insert, template as _$template } from "r-dom";
+/** @jsxImportSource r-dom */ import { state } from "./store";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div class="greeting">Hello `);
+export const template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+    _$insert(_el$, ()=>state.name, null);
+    return _el$;
+})();
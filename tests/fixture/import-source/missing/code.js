@@ -0,0 +1,3 @@
+import { state } from "./store";
+
+export const template = <div className="greeting">Hello {state.name}</div>;
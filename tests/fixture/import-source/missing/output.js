@@ -0,0 +1,2 @@
+import { state } from "./store";
+export const template = <div className="greeting">Hello {state.name}</div>;
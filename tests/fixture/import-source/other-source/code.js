@@ -0,0 +1,4 @@
+/** @jsxImportSource react */
+import { state } from "./store";
+
+export const template = <div className="greeting">Hello {state.name}</div>;
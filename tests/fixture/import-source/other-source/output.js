@@ -0,0 +1,2 @@
+/** @jsxImportSource react */ import { state } from "./store";
+export const template = <div className="greeting">Hello {state.name}</div>;
@@ -0,0 +1,3 @@
+export const accents = <div>é café   crème</div>;
+export const emoji = <p title="résumé 🎉">🎉   party <b>ünïcödé</b>   時間</p>;
+export const attribute = <span data-ключ="значение">Привет   мир</span>;
@@ -0,0 +1,5 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>é café crème`), _tmpl$2 = /*#__PURE__*/ _$template(`<p title="résumé 🎉">🎉 party <b>ünïcödé</b> 時間`), _tmpl$3 = /*#__PURE__*/ _$template(`<span data-ключ=значение>Привет мир`);
+export const accents = _tmpl$();
+export const emoji = _tmpl$2();
+export const attribute = _tmpl$3();
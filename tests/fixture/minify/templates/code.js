@@ -0,0 +1,50 @@
+export const list = (
+  <ul class="list">
+    <li class="item active">One</li>
+    <li class="item">Two</li>
+    <li>{three()}</li>
+  </ul>
+);
+
+export const form = (
+  <form>
+    <input type="checkbox" checked="checked" disabled="" />
+    <select>
+      <option value="a" selected>A</option>
+      <option value="b">B</option>
+    </select>
+    <p>First  paragraph   with   spaces</p>
+    <p>Second</p>
+    <div data-label="a b">done</div>
+  </form>
+);
+
+export const table = (
+  <table>
+    <tbody>
+      <tr>
+        <td>1</td>
+        <td>2</td>
+      </tr>
+      <tr>
+        <td>3</td>
+        <td>{four()}</td>
+      </tr>
+    </tbody>
+  </table>
+);
+
+export const inLink = (
+  <a href="/home">
+    <p>kept</p>
+  </a>
+);
+
+export const pre = <pre>{"  keep   this  "}</pre>;
+
+export const icon = (
+  <svg viewBox="0 0 10 10">
+    <circle r="5" />
+    <rect width="2" height="2" />
+  </svg>
+);
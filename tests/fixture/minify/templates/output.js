@@ -0,0 +1,16 @@
+import { insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<ul class=list><li class="item active">One<li class=item>Two<li>`), _tmpl$2 = /*#__PURE__*/ _$template(`<form><input type=checkbox checked disabled><select><option value=a selected>A<option value=b>B</select><p>First paragraph with spaces<p>Second<div data-label="a b">done`), _tmpl$3 = /*#__PURE__*/ _$template(`<table><tbody><tr><td>1<td>2<tr><td>3<td>`), _tmpl$4 = /*#__PURE__*/ _$template(`<a href=/home><p>kept`), _tmpl$5 = /*#__PURE__*/ _$template(`<pre>  keep   this  `), _tmpl$6 = /*#__PURE__*/ _$template(`<svg viewBox="0 0 10 10"><circle r=5></circle><rect width=2 height=2>`);
+export const list = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling;
+    _$insert(_el$4, three);
+    return _el$;
+})();
+export const form = _tmpl$2();
+export const table = (()=>{
+    const _el$6 = _tmpl$3(), _el$7 = _el$6.firstChild, _el$8 = _el$7.firstChild, _el$9 = _el$8.nextSibling, _el$10 = _el$9.firstChild, _el$11 = _el$10.nextSibling;
+    _$insert(_el$11, four);
+    return _el$6;
+})();
+export const inLink = _tmpl$4();
+export const pre = _tmpl$5();
+export const icon = _tmpl$6();
@@ -0,0 +1,6 @@
+export const widget = (
+  <div onScroll={handleScroll} onInput={handleInput}>
+    <button onClick={increment}>+</button>
+    <button onClick={[select, id]}>Select</button>
+  </div>
+);
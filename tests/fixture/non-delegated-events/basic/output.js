@@ -0,0 +1,13 @@
+import { addEventListener as _$addEventListener, delegateEvents as _$delegateEvents, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div><button>+</button><button>Select`);
+export const widget = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+    _$addEventListener(_el$, "input", handleInput, true);
+    _$addEventListener(_el$, "scroll", handleScroll);
+    _$addEventListener(_el$2, "click", increment);
+    _el$3.addEventListener("click", (e)=>select(id, e));
+    return _el$;
+})();
+_$delegateEvents([
+    "input"
+]);
@@ -0,0 +1,2 @@
+// @dom-expressions hydratable delegateEvents=false
+export const view = <button onClick={increment}>{count()}</button>;
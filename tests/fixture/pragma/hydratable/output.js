@@ -0,0 +1,9 @@
+import { addEventListener as _$addEventListener, getNextElement as _$getNextElement, insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button>`);
+// @dom-expressions hydratable delegateEvents=false
+export const view = (()=>{
+    const _el$ = _$getNextElement(_tmpl$);
+    _$addEventListener(_el$, "click", increment);
+    _$insert(_el$, count);
+    return _el$;
+})();
@@ -0,0 +1,2 @@
+/** @dom-expressions generate=srr hydrate */
+export const view = <div>{count()}</div>;
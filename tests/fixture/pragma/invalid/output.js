@@ -0,0 +1,7 @@
+import { insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div>`);
+/** @dom-expressions generate=srr hydrate */ export const view = (()=>{
+    const _el$ = _tmpl$();
+    _$insert(_el$, count);
+    return _el$;
+})();
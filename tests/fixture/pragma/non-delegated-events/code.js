@@ -0,0 +1,2 @@
+/* @dom-expressions nonDelegatedEvents=input,keydown */
+export const field = <input onInput={update} onKeyDown={submit} onClick={focus} />;
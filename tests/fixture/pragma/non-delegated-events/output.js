@@ -0,0 +1,12 @@
+import { addEventListener as _$addEventListener, delegateEvents as _$delegateEvents, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<input>`);
+/* @dom-expressions nonDelegatedEvents=input,keydown */ export const field = (()=>{
+    const _el$ = _tmpl$();
+    _$addEventListener(_el$, "click", focus, true);
+    _$addEventListener(_el$, "keydown", submit);
+    _$addEventListener(_el$, "input", update);
+    return _el$;
+})();
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,2 @@
+/** @dom-expressions generate=ssr moduleName=r-server */
+export const island = <div class="island">{count()}</div>;
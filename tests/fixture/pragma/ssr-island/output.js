@@ -0,0 +1,6 @@
+import { escape as _$escape, ssr as _$ssr } from "r-server";
+var _tmpl$ = [
+    '<div class="island">',
+    "</div>"
+];
+/** @dom-expressions generate=ssr moduleName=r-server */ export const island = _$ssr(_tmpl$, _$escape(count()));
@@ -0,0 +1,9 @@
+function App(props) {
+  return (
+    <Show when={props.visible}>
+      <For each={props.items}>{item => <li>{item.name}</li>}</For>
+    </Show>
+  );
+}
+
+module.exports = App;
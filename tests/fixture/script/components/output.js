@@ -0,0 +1,22 @@
+const { For: _$For, Show: _$Show, createComponent: _$createComponent, insert: _$insert, template: _$template } = require("r-dom");
+const _tmpl$ = /*#__PURE__*/ _$template(`<li>`);
+function App(props) {
+    return _$createComponent(_$Show, {
+        get when () {
+            return props.visible;
+        },
+        get children () {
+            return _$createComponent(_$For, {
+                get each () {
+                    return props.items;
+                },
+                children: (item)=>(()=>{
+                        const _el$ = _tmpl$();
+                        _$insert(_el$, ()=>item.name);
+                        return _el$;
+                    })()
+            });
+        }
+    });
+}
+module.exports = App;
@@ -0,0 +1,9 @@
+"use strict";
+
+const label = "Count";
+var template = (
+  <div id="main">
+    <h1>{label}</h1>
+    <button onClick={() => setCount(count() + 1)}>{count()}</button>
+  </div>
+);
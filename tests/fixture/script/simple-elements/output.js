@@ -0,0 +1,13 @@
+"use strict";
+const { delegateEvents: _$delegateEvents, insert: _$insert, template: _$template } = require("r-dom");
+const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><h1>Count</h1><button>`);
+const label = "Count";
+var template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+    _el$3.$$click = ()=>setCount(count() + 1);
+    _$insert(_el$3, count);
+    return _el$;
+})();
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,15 @@
+import { template } from "r-dom";
+
+export const rows = [<div class="row">{a()}</div>, <div class="row">{b()}</div>];
+
+export const icon = (
+  <svg viewBox="0 0 10 10">
+    <circle r="5" />
+  </svg>
+);
+
+export const custom = <my-element label="x" />;
+
+export const raw = template;
+
+export const closing = <p>1 */ 2</p>;
@@ -0,0 +1,18 @@
+/* @shared-templates [{"name":"tmpl_e8309bc46d70d9d3","template":"<div class=\"row\">","isSvg":false,"isCe":false},{"name":"tmpl_97a311d4c8ed4e60","template":"<svg viewBox=\"0 0 10 10\"><circle r=\"5\">","isSvg":false,"isCe":false},{"name":"tmpl_36ee734d09e37d7a","template":"<my-element label=\"x\">","isSvg":false,"isCe":true},{"name":"tmpl_9f2a52a7d6bab8d1","template":"<p>1 *\/ 2","isSvg":false,"isCe":false}] */ import { tmpl_36ee734d09e37d7a as _tmpl$3, tmpl_97a311d4c8ed4e60 as _tmpl$2, tmpl_9f2a52a7d6bab8d1 as _tmpl$4, tmpl_e8309bc46d70d9d3 as _tmpl$ } from "virtual:dom-templates";
+import { template, insert as _$insert } from "r-dom";
+export const rows = [
+    (()=>{
+        const _el$ = _tmpl$();
+        _$insert(_el$, a);
+        return _el$;
+    })(),
+    (()=>{
+        const _el$2 = _tmpl$();
+        _$insert(_el$2, b);
+        return _el$2;
+    })()
+];
+export const icon = _tmpl$2();
+export const custom = _tmpl$3();
+export const raw = template;
+export const closing = _tmpl$4();
@@ -0,0 +1,14 @@
+import { Child } from "./child";
+
+export const template = (
+  <div id="main">
+    <span
+      class={state.name}
+      title={state.title}
+    >
+      {state.count}
+    </span>
+    <button onClick={increment}>+</button>
+    <Child name={state.name} />
+  </div>
+);
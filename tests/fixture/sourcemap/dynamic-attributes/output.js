@@ -0,0 +1,26 @@
+import { addEventListener as _$addEventListener, className as _$className, createComponent as _$createComponent, delegateEvents as _$delegateEvents, effect as _$effect, insert as _$insert, setAttribute as _$setAttribute, template as _$template } from "r-dom";
+import { Child } from "./child";
+const _tmpl$ = /*#__PURE__*/ _$template(`<div id="main"><span></span><button>+`);
+export const template = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+    _$insert(_el$2, ()=>state.count);
+    _$addEventListener(_el$3, "click", increment, true);
+    _$insert(_el$, _$createComponent(Child, {
+        get name () {
+            return state.name;
+        }
+    }), null);
+    _$effect((_p$)=>{
+        const _v$ = state.name, _v$2 = state.title;
+        _v$ !== _p$._v$ && _$className(_el$2, _p$._v$ = _v$);
+        _v$2 !== _p$._v$2 && _$setAttribute(_el$2, "title", _p$._v$2 = _v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$;
+})();
+_$delegateEvents([
+    "click"
+]);
@@ -0,0 +1,44 @@
+const selected = true;
+let id = "my-h1";
+let link;
+export const template = (
+  <div id="main" {...results} classList={{ selected: unknown }} style={{ color }}>
+    <h1
+      class="base"
+      id={id}
+      {...results()}
+      disabled
+      readonly=""
+      title={welcoming()}
+      style={{ "background-color": color(), "margin-right": "40px" }}
+      classList={{ dynamic: dynamic(), selected }}
+    >
+      <a href={"/"} ref={link} classList={{ "ccc ddd": true }}>
+        Welcome
+      </a>
+    </h1>
+  </div>
+);
+
+export const template2 = (
+  <div
+    id="main"
+    title={welcoming()}
+    class={color()}
+    classList={{ selected: isSelected() }}
+    style={{ color: color(), "font-size": size + "px" }}
+    style:padding={padding()}
+    class:active={active()}
+    onClick={() => console.log("clicked")}
+    ref={el}
+    use:tooltip={tip}
+  >
+    <input type="checkbox" checked={checked()} disabled={false} />
+  </div>
+);
+
+export const template3 = <div style={style()} aria-label={props.label} textContent={text()} />;
+
+export const template4 = <div innerHTML={html} />;
+
+export const template5 = <div attr:data-id={id} data-static="a&b" />;
@@ -0,0 +1,66 @@
+import { escape as _$escape, mergeProps as _$mergeProps, ssr as _$ssr, ssrAttribute as _$ssrAttribute, ssrClassList as _$ssrClassList, ssrElement as _$ssrElement, ssrStyle as _$ssrStyle } from "r-server";
+var _tmpl$ = [
+    '<a href="/" class="',
+    '">Welcome</a>'
+], _tmpl$2 = [
+    '<div id="main"',
+    ' class="',
+    " ",
+    '" style="',
+    '"><input type="checkbox"',
+    "></div>"
+], _tmpl$3 = [
+    '<div style="',
+    '"',
+    ">",
+    "</div>"
+], _tmpl$4 = [
+    "<div>",
+    "</div>"
+], _tmpl$5 = [
+    "<div",
+    ' data-static="a&amp;b"></div>'
+];
+const selected = true;
+let id = "my-h1";
+let link;
+export const template = _$ssrElement("div", _$mergeProps({
+    id: "main"
+}, results, {
+    classList: {
+        selected: unknown
+    },
+    style: {
+        color
+    }
+}), _$ssrElement("h1", _$mergeProps({
+    "class": "base",
+    id: id
+}, results, {
+    disabled: true,
+    readonly: "",
+    get title () {
+        return welcoming();
+    },
+    get style () {
+        return {
+            "background-color": color(),
+            "margin-right": "40px"
+        };
+    },
+    get classList () {
+        return {
+            dynamic: dynamic(),
+            selected
+        };
+    }
+}), _$ssr(_tmpl$, _$ssrClassList({
+    "ccc ddd": true
+})), false), false);
+export const template2 = _$ssr(_tmpl$2, _$ssrAttribute("title", _$escape(welcoming(), true), false), _$escape(color(), true), _$ssrClassList({
+    selected: isSelected(),
+    "active": active()
+}), "color:" + _$escape(color(), true) + ";font-size:" + (_$escape(size, true) + "px") + ";padding:" + _$escape(padding(), true), _$ssrAttribute("checked", checked(), true));
+export const template3 = _$ssr(_tmpl$3, _$ssrStyle(style()), _$ssrAttribute("aria-label", _$escape(props.label, true), false), _$escape(text()));
+export const template4 = _$ssr(_tmpl$4, html);
+export const template5 = _$ssr(_tmpl$5, _$ssrAttribute("data-id", _$escape(id, true), false));
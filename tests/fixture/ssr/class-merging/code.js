@@ -0,0 +1,6 @@
+export const staticWithList = <div class="static" classList={{ a: b() }} />;
+export const dynamicWithList = <div id="main" class={c()} classList={list()} title="t" />;
+export const classAndClassName = <div class="a" className={d()} />;
+export const namespaced = <div class="base" class:active={isActive()} />;
+export const single = <div class={c()} />;
+export const listOnly = <div classList={{ a: b() }} />;
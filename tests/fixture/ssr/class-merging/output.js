@@ -0,0 +1,33 @@
+import { escape as _$escape, ssr as _$ssr, ssrAttribute as _$ssrAttribute, ssrClassList as _$ssrClassList } from "r-server";
+var _tmpl$ = [
+    '<div class="static ',
+    '"></div>'
+], _tmpl$2 = [
+    '<div id="main" class="',
+    " ",
+    '" title="t"></div>'
+], _tmpl$3 = [
+    '<div class="a ',
+    '"></div>'
+], _tmpl$4 = [
+    '<div class="base ',
+    '"></div>'
+], _tmpl$5 = [
+    "<div",
+    "></div>"
+], _tmpl$6 = [
+    '<div class="',
+    '"></div>'
+];
+export const staticWithList = _$ssr(_tmpl$, _$ssrClassList({
+    a: b()
+}));
+export const dynamicWithList = _$ssr(_tmpl$2, _$escape(c(), true), _$ssrClassList(list()));
+export const classAndClassName = _$ssr(_tmpl$3, _$escape(d(), true));
+export const namespaced = _$ssr(_tmpl$4, _$ssrClassList({
+    "active": isActive()
+}));
+export const single = _$ssr(_tmpl$5, _$ssrAttribute("class", _$escape(c(), true), false));
+export const listOnly = _$ssr(_tmpl$6, _$ssrClassList({
+    a: b()
+}));
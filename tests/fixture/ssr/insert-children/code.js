@@ -0,0 +1,22 @@
+const children = <div />;
+const dynamic = {
+  children
+};
+export const template = <Module children={children} />;
+export const template2 = <module children={children} />;
+export const template3 = <module children={children}>Hello</module>;
+export const template4 = (
+  <module children={children}>
+    <Hello />
+  </module>
+);
+export const template5 = <module>{dynamic.children}</module>;
+export const template6 = <Module>{dynamic.children}</Module>;
+export const template7 = <div>{state.loading ? <span>Loading</span> : state.name}</div>;
+export const template8 = (
+  <div>
+    Hi {name()}, {count() + 1} items {list().length > 0 && <ul>{list()}</ul>}
+  </div>
+);
+export const template9 = <div {...props}>Hello {name}<span>!</span></div>;
+export const template10 = <script>{"if (a < b && c) {}"}</script>;
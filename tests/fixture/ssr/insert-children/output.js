@@ -0,0 +1,40 @@
+import { createComponent as _$createComponent, escape as _$escape, ssr as _$ssr, ssrElement as _$ssrElement } from "r-server";
+var _tmpl$ = "<div></div>", _tmpl$2 = [
+    "<module>",
+    "</module>"
+], _tmpl$3 = "<module>Hello</module>", _tmpl$4 = [
+    "<div>",
+    "</div>"
+], _tmpl$5 = "<span>Loading</span>", _tmpl$6 = [
+    "<div>Hi ",
+    ", ",
+    " items ",
+    "</div>"
+], _tmpl$7 = [
+    "<ul>",
+    "</ul>"
+], _tmpl$8 = "<span>!</span>", _tmpl$9 = "<script>if (a < b && c) {}</script>";
+const children = _$ssr(_tmpl$);
+const dynamic = {
+    children
+};
+export const template = _$createComponent(Module, {
+    children: children
+});
+export const template2 = _$ssr(_tmpl$2, _$escape(children));
+export const template3 = _$ssr(_tmpl$3);
+export const template4 = _$ssr(_tmpl$2, _$escape(_$createComponent(Hello, {})));
+export const template5 = _$ssr(_tmpl$2, _$escape(dynamic.children));
+export const template6 = _$createComponent(Module, {
+    get children () {
+        return dynamic.children;
+    }
+});
+export const template7 = _$ssr(_tmpl$4, state.loading ? _$ssr(_tmpl$5) : _$escape(state.name));
+export const template8 = _$ssr(_tmpl$6, _$escape(name()), _$escape(count()) + 1, list().length > 0 && _$ssr(_tmpl$7, _$escape(list())));
+export const template9 = _$ssrElement("div", props, [
+    "Hello ",
+    _$escape(name),
+    _$ssr(_tmpl$8)
+], false);
+export const template10 = _$ssr(_tmpl$9);
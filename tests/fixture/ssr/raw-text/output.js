@@ -0,0 +1,18 @@
+import { ssr as _$ssr } from "r-server";
+var _tmpl$ = '<style>\n    a > b { content: "&"; }\n    .card:not(.active) { color: red; }\n  </style>', _tmpl$2 = [
+    "<style>",
+    "</style>"
+], _tmpl$3 = [
+    "<style>\n    .theme { color: ",
+    "; background: `url($",
+    ")`; }\n  </style>"
+], _tmpl$4 = '<script type="module">\n    if (a < b && c) run()\n    // keep lines apart\n    done()\n  </script>', _tmpl$5 = [
+    '<script type="application/json">',
+    "</script>"
+];
+const accent = "red";
+export const staticStyle = _$ssr(_tmpl$);
+export const dynamicStyle = _$ssr(_tmpl$2, css());
+export const mixedStyle = _$ssr(_tmpl$3, color(), image);
+export const staticScript = _$ssr(_tmpl$4);
+export const dynamicScript = _$ssr(_tmpl$5, JSON.stringify(data));
@@ -0,0 +1,23 @@
+export const template = (
+  <div id="main">
+    <style>{"div { color: red; }"}</style>
+    <h1>Welcome</h1>
+    <label for={"entry"}>Edit:</label>
+    <input id="entry" type="text" />
+    {/* Comment Node */}
+  </div>
+);
+
+export const template2 = (
+  <div>
+    <span>
+      <a></a>
+    </span>
+    <span />
+  </div>
+);
+
+export const template3 = <div>`backticks` & "quotes"</div>;
+
+export const template4 = <>Hello</>;
+export const template5 = <>{"1"}{props.id}</>;
@@ -0,0 +1,10 @@
+import { ssr as _$ssr } from "r-server";
+var _tmpl$ = '<div id="main"><style>div { color: red; }</style><h1>Welcome</h1><label for="entry">Edit:</label><input id="entry" type="text"></div>', _tmpl$2 = "<div><span><a></a></span><span></span></div>", _tmpl$3 = '<div>`backticks` &amp; "quotes"</div>';
+export const template = _$ssr(_tmpl$);
+export const template2 = _$ssr(_tmpl$2);
+export const template3 = _$ssr(_tmpl$3);
+export const template4 = "Hello";
+export const template5 = [
+    "1",
+    props.id
+];
@@ -0,0 +1,17 @@
+export const pre = (
+  <pre>
+  indented
+    <b>  bold  </b>   {value}
+</pre>
+);
+
+export const spread = <pre {...props}>
+  keep   me
+</pre>;
+
+export const collapsed = <code>a  =
+  1;</code>;
+
+export const leadingNewline = <textarea>
+{value}
+</textarea>;
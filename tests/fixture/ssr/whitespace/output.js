@@ -0,0 +1,12 @@
+import { escape as _$escape, ssr as _$ssr, ssrElement as _$ssrElement } from "r-server";
+var _tmpl$ = [
+    "<pre>\n\n  indented\n    <b>  bold  </b>   ",
+    "\n</pre>"
+], _tmpl$2 = "<code>a = 1;</code>", _tmpl$3 = [
+    "<textarea>\n\n",
+    "\n</textarea>"
+];
+export const pre = _$ssr(_tmpl$, _$escape(value));
+export const spread = _$ssrElement("pre", props, "\n\n  keep   me\n", false);
+export const collapsed = _$ssr(_tmpl$2);
+export const leadingNewline = _$ssr(_tmpl$3, _$escape(value));
@@ -0,0 +1,12 @@
+export const header = <header class="top">{title()}</header>;
+
+export const list = (
+  <ul>
+    <li class="row">{first()}</li>
+    <li class="row">{second()}</li>
+  </ul>
+);
+
+export const rows = [<div class="row">{a()}</div>, <div class="row">{b()}</div>];
+
+export const footer = <footer>{year()}</footer>;
@@ -0,0 +1,30 @@
+import { insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$2ce5e9bf = /*#__PURE__*/ _$template(`<header class="top">`), _tmpl$bd8d8149 = /*#__PURE__*/ _$template(`<ul><li class="row"></li><li class="row">`), _tmpl$f43aa8a4 = /*#__PURE__*/ _$template(`<div class="row">`), _tmpl$2c3483ca = /*#__PURE__*/ _$template(`<footer>`);
+export const header = (()=>{
+    const _el$ = _tmpl$2ce5e9bf();
+    _$insert(_el$, title);
+    return _el$;
+})();
+export const list = (()=>{
+    const _el$ = _tmpl$bd8d8149(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+    _$insert(_el$2, first);
+    _$insert(_el$3, second);
+    return _el$;
+})();
+export const rows = [
+    (()=>{
+        const _el$ = _tmpl$f43aa8a4();
+        _$insert(_el$, a);
+        return _el$;
+    })(),
+    (()=>{
+        const _el$ = _tmpl$f43aa8a4();
+        _$insert(_el$, b);
+        return _el$;
+    })()
+];
+export const footer = (()=>{
+    const _el$ = _tmpl$2c3483ca();
+    _$insert(_el$, year);
+    return _el$;
+})();
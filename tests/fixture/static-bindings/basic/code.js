@@ -0,0 +1,20 @@
+import { t } from "./i18n";
+import styles from "./Button.module.css";
+import { tracked } from "./store";
+
+export const button = (
+  <button class={cn(styles.button, "primary")} title={t("save")}>
+    {t("hello")}
+    {intl.format(price)}
+  </button>
+);
+
+export const stillDynamic = (
+  <div class={cn(styles.button, tracked())} title={styles[variant()]}>
+    {t(count())}
+    {tracked.label}
+    {format(price)}
+  </div>
+);
+
+export const component = <Label text={t("name")} class={styles.label} value={tracked.value} />;
@@ -0,0 +1,36 @@
+import { className as _$className, createComponent as _$createComponent, effect as _$effect, insert as _$insert, setAttribute as _$setAttribute, template as _$template } from "r-dom";
+import { t } from "./i18n";
+import styles from "./Button.module.css";
+import { tracked } from "./store";
+const _tmpl$ = /*#__PURE__*/ _$template(`<button>`), _tmpl$2 = /*#__PURE__*/ _$template(`<div>`);
+export const button = (()=>{
+    const _el$ = _tmpl$();
+    _$className(_el$, cn(styles.button, "primary"));
+    _$setAttribute(_el$, "title", t("save"));
+    _$insert(_el$, t("hello"), null);
+    _$insert(_el$, intl.format(price), null);
+    return _el$;
+})();
+export const stillDynamic = (()=>{
+    const _el$2 = _tmpl$2();
+    _$insert(_el$2, ()=>t(count()), null);
+    _$insert(_el$2, ()=>tracked.label, null);
+    _$insert(_el$2, ()=>format(price), null);
+    _$effect((_p$)=>{
+        const _v$ = cn(styles.button, tracked()), _v$2 = styles[variant()];
+        _v$ !== _p$._v$ && _$className(_el$2, _p$._v$ = _v$);
+        _v$2 !== _p$._v$2 && _$setAttribute(_el$2, "title", _p$._v$2 = _v$2);
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$2;
+})();
+export const component = _$createComponent(Label, {
+    text: t("name"),
+    "class": styles.label,
+    get value () {
+        return tracked.value;
+    }
+});
@@ -0,0 +1,12 @@
+const selected = true;
+let id = "my-h1";
+let link;
+export const template = (
+  <box id="main" title={welcoming()} color={state.color} flag>
+    <text ref={link} use:tooltip={label}>Welcome</text>
+  </box>
+);
+
+export const template2 = <rect {...props} width={width()} visible />;
+
+export const template3 = <rect x={x()} />;
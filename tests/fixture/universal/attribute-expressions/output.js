@@ -0,0 +1,38 @@
+import { createElement as _$createElement, createTextNode as _$createTextNode, effect as _$effect, insertNode as _$insertNode, mergeProps as _$mergeProps, setProp as _$setProp, spread as _$spread, use as _$use } from "r-custom";
+const selected = true;
+let id = "my-h1";
+let link;
+export const template = (()=>{
+    const _el$ = _$createElement("box"), _ref$ = link, _el$2 = _$createElement("text");
+    _$insertNode(_el$, _el$2);
+    _$setProp(_el$, "id", "main");
+    _$setProp(_el$, "flag", true);
+    _$insertNode(_el$2, _$createTextNode("Welcome"));
+    _$use(tooltip, _el$2, ()=>label);
+    typeof _ref$ === "function" ? _$use(_ref$, _el$2) : link = _el$2;
+    _$effect((_p$)=>{
+        const _v$ = welcoming(), _v$2 = state.color;
+        _v$ !== _p$._v$ && (_p$._v$ = _$setProp(_el$, "title", _v$, _p$._v$));
+        _v$2 !== _p$._v$2 && (_p$._v$2 = _$setProp(_el$, "color", _v$2, _p$._v$2));
+        return _p$;
+    }, {
+        _v$: undefined,
+        _v$2: undefined
+    });
+    return _el$;
+})();
+export const template2 = (()=>{
+    const _el$4 = _$createElement("rect");
+    _$spread(_el$4, _$mergeProps(props, {
+        get width () {
+            return width();
+        },
+        visible: true
+    }), false);
+    return _el$4;
+})();
+export const template3 = (()=>{
+    const _el$5 = _$createElement("rect");
+    _$effect((_$p)=>_$setProp(_el$5, "x", x(), _$p));
+    return _el$5;
+})();
@@ -0,0 +1,19 @@
+const children = <text />;
+const dynamic = {
+  children
+};
+export const template = <box>{children}</box>;
+export const template2 = (
+  <box>
+    <text>Before</text>
+    {state.name}
+    <text>After</text>
+  </box>
+);
+export const template3 = <box>Hello {name()}</box>;
+export const template4 = (
+  <box>
+    <Component />
+    {list.map(item => <text>{item}</text>)}
+  </box>
+);
@@ -0,0 +1,35 @@
+import { createComponent as _$createComponent, createElement as _$createElement, createTextNode as _$createTextNode, insert as _$insert, insertNode as _$insertNode } from "r-custom";
+const children = _$createElement("text");
+const dynamic = {
+    children
+};
+export const template = (()=>{
+    const _el$2 = _$createElement("box");
+    _$insert(_el$2, children);
+    return _el$2;
+})();
+export const template2 = (()=>{
+    const _el$3 = _$createElement("box"), _el$4 = _$createElement("text"), _el$6 = _$createElement("text");
+    _$insertNode(_el$3, _el$4);
+    _$insertNode(_el$3, _el$6);
+    _$insertNode(_el$4, _$createTextNode("Before"));
+    _$insert(_el$3, ()=>state.name, _el$6);
+    _$insertNode(_el$6, _$createTextNode("After"));
+    return _el$3;
+})();
+export const template3 = (()=>{
+    const _el$8 = _$createElement("box"), _el$9 = _$createTextNode("Hello ");
+    _$insertNode(_el$8, _el$9);
+    _$insert(_el$8, name, null);
+    return _el$8;
+})();
+export const template4 = (()=>{
+    const _el$10 = _$createElement("box");
+    _$insert(_el$10, _$createComponent(Component, {}), null);
+    _$insert(_el$10, ()=>list.map((item)=>(()=>{
+                const _el$11 = _$createElement("text");
+                _$insert(_el$11, item);
+                return _el$11;
+            })()), null);
+    return _el$10;
+})();
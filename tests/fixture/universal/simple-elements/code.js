@@ -0,0 +1,12 @@
+export const template = (
+  <box id="main">
+    <text>Hello &amp; welcome</text>
+    <text>
+      Multi   line
+      text
+    </text>
+    <rect width={10} />
+  </box>
+);
+
+export const template2 = <text>Static</text>;
@@ -0,0 +1,17 @@
+import { createElement as _$createElement, createTextNode as _$createTextNode, insertNode as _$insertNode, setProp as _$setProp } from "r-custom";
+export const template = (()=>{
+    const _el$ = _$createElement("box"), _el$2 = _$createElement("text"), _el$4 = _$createElement("text"), _el$6 = _$createElement("rect");
+    _$insertNode(_el$, _el$2);
+    _$insertNode(_el$, _el$4);
+    _$insertNode(_el$, _el$6);
+    _$setProp(_el$, "id", "main");
+    _$insertNode(_el$2, _$createTextNode("Hello & welcome"));
+    _$insertNode(_el$4, _$createTextNode("Multi line text"));
+    _$setProp(_el$6, "width", 10);
+    return _el$;
+})();
+export const template2 = (()=>{
+    const _el$7 = _$createElement("text");
+    _$insertNode(_el$7, _$createTextNode("Static"));
+    return _el$7;
+})();
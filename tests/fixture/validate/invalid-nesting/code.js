@@ -0,0 +1,43 @@
+export const template = (
+  <p>
+    <div>Block in paragraph</div>
+  </p>
+);
+
+export const template2 = (
+  <table>
+    <tr>
+      <td>Missing tbody</td>
+    </tr>
+  </table>
+);
+
+export const template3 = (
+  <a href="/">
+    <a href="/nested">Nested link</a>
+  </a>
+);
+
+export const template4 = (
+  <a href="/">
+    <span>
+      <a href="/nested">Deeply nested link</a>
+    </span>
+  </a>
+);
+
+export const template5 = (
+  <p>
+    <span>
+      <div>Block deep in paragraph</div>
+    </span>
+  </p>
+);
+
+export const template6 = (
+  <svg>
+    <g>
+      <div>HTML deep in svg</div>
+    </g>
+  </svg>
+);
@@ -0,0 +1,8 @@
+import { template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<p><div>Block in paragraph`), _tmpl$2 = /*#__PURE__*/ _$template(`<table><tr><td>Missing tbody`), _tmpl$3 = /*#__PURE__*/ _$template(`<a href="/"><a href="/nested">Nested link`), _tmpl$4 = /*#__PURE__*/ _$template(`<a href="/"><span><a href="/nested">Deeply nested link`), _tmpl$5 = /*#__PURE__*/ _$template(`<p><span><div>Block deep in paragraph`), _tmpl$6 = /*#__PURE__*/ _$template(`<svg><g><div>HTML deep in svg`);
+export const template = _tmpl$();
+export const template2 = _tmpl$2();
+export const template3 = _tmpl$3();
+export const template4 = _tmpl$4();
+export const template5 = _tmpl$5();
+export const template6 = _tmpl$6();
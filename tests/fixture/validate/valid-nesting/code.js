@@ -0,0 +1,61 @@
+export const template = (
+  <table>
+    <tbody>
+      <tr>
+        <td>Cell</td>
+      </tr>
+    </tbody>
+  </table>
+);
+
+export const template2 = (
+  <p>
+    <span>Inline</span> <Component />
+  </p>
+);
+
+export const template3 = (
+  <select>
+    <option value="a">A</option>
+  </select>
+);
+
+export const template4 = (
+  <ul>
+    <li>
+      <ul>
+        <li>Nested list</li>
+      </ul>
+    </li>
+  </ul>
+);
+
+export const template5 = (
+  <p>
+    <button>
+      <div>Block in button</div>
+    </button>
+  </p>
+);
+
+export const template6 = (
+  <h1>
+    <span>
+      <h2>Heading below an inline element</h2>
+    </span>
+  </h1>
+);
+
+export const template7 = (
+  <svg>
+    <foreignObject>
+      <div>HTML in foreignObject</div>
+    </foreignObject>
+  </svg>
+);
+
+export const template8 = (
+  <head>
+    <basefont />
+  </head>
+);
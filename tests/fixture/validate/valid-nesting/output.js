@@ -0,0 +1,14 @@
+import { createComponent as _$createComponent, insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<table><tbody><tr><td>Cell`), _tmpl$2 = /*#__PURE__*/ _$template(`<p><span>Inline</span> `), _tmpl$3 = /*#__PURE__*/ _$template(`<select><option value="a">A`), _tmpl$4 = /*#__PURE__*/ _$template(`<ul><li><ul><li>Nested list`), _tmpl$5 = /*#__PURE__*/ _$template(`<p><button><div>Block in button`), _tmpl$6 = /*#__PURE__*/ _$template(`<h1><span><h2>Heading below an inline element`), _tmpl$7 = /*#__PURE__*/ _$template(`<svg><foreignObject><div>HTML in foreignObject`), _tmpl$8 = /*#__PURE__*/ _$template(`<head><basefont>`);
+export const template = _tmpl$();
+export const template2 = (()=>{
+    const _el$2 = _tmpl$2(), _el$3 = _el$2.firstChild, _el$4 = _el$3.nextSibling;
+    _$insert(_el$2, _$createComponent(Component, {}), null);
+    return _el$2;
+})();
+export const template3 = _tmpl$3();
+export const template4 = _tmpl$4();
+export const template5 = _tmpl$5();
+export const template6 = _tmpl$6();
+export const template7 = _tmpl$7();
+export const template8 = _tmpl$8();
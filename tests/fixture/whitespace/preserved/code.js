@@ -0,0 +1,35 @@
+export const pre = (
+  <pre>
+  fn main() -&gt; Result
+      println!("&lt;hello&gt; &amp; `world` ${name}");
+  done
+</pre>
+);
+
+export const nested = (
+  <div>
+    <pre>  <b>bold</b>   and   {value}
+      trailing   </pre>
+    <p>
+      collapsed    as
+      usual
+    </p>
+  </div>
+);
+
+export const textarea = <textarea>
+  line one
+    line two
+</textarea>;
+
+export const code = <code>a  =  1;
+b  =  2;</code>;
+
+export const leadingNewline = <pre>
+{value}</pre>;
+
+export const trailingNewline = <pre>{value}
+</pre>;
+
+export const listing = <listing>
+  not preserved</listing>;
@@ -0,0 +1,38 @@
+import { insert as _$insert, template as _$template } from "r-dom";
+const _tmpl$ = /*#__PURE__*/ _$template(`<pre>
+
+  fn main() -&gt; Result
+      println!("&lt;hello&gt; &amp; \`world\` $<!>");
+  done
+`), _tmpl$2 = /*#__PURE__*/ _$template(`<div><pre>  <b>bold</b>   and   <!>
+      trailing   </pre><p>collapsed as usual`), _tmpl$3 = /*#__PURE__*/ _$template(`<textarea>
+
+  line one
+    line two
+`), _tmpl$4 = /*#__PURE__*/ _$template(`<code>a  =  1;
+b  =  2;`), _tmpl$5 = /*#__PURE__*/ _$template(`<pre>
+
+`), _tmpl$6 = /*#__PURE__*/ _$template(`<listing>not preserved`);
+export const pre = (()=>{
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$4 = _el$2.nextSibling, _el$3 = _el$4.nextSibling;
+    _$insert(_el$, name, _el$4);
+    return _el$;
+})();
+export const nested = (()=>{
+    const _el$5 = _tmpl$2(), _el$6 = _el$5.firstChild, _el$7 = _el$6.firstChild, _el$8 = _el$7.nextSibling, _el$9 = _el$8.nextSibling, _el$11 = _el$9.nextSibling, _el$10 = _el$11.nextSibling;
+    _$insert(_el$6, value, _el$11);
+    return _el$5;
+})();
+export const textarea = _tmpl$3();
+export const code = _tmpl$4();
+export const leadingNewline = (()=>{
+    const _el$14 = _tmpl$5(), _el$15 = _el$14.firstChild;
+    _$insert(_el$14, value, null);
+    return _el$14;
+})();
+export const trailingNewline = (()=>{
+    const _el$16 = _tmpl$5(), _el$17 = _el$16.firstChild;
+    _$insert(_el$16, value, _el$17);
+    return _el$16;
+})();
+export const listing = _tmpl$6();
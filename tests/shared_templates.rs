@@ -0,0 +1,80 @@
+use jsx_dom_expressions::{SharedTemplate, read_shared_templates, render_shared_templates};
+
+fn shared_template(name: &str, template: &str, is_svg: bool, is_ce: bool) -> SharedTemplate {
+    SharedTemplate {
+        name: name.to_string(),
+        template: template.to_string(),
+        is_svg,
+        is_ce,
+    }
+}
+
+#[test]
+fn renders_shared_templates_module() {
+    let templates = [
+        shared_template("tmpl_1", "<div class=\"row\">", false, false),
+        shared_template("tmpl_2", "<svg><circle r=\"5\">", true, false),
+        shared_template("tmpl_3", "<my-element label=\"x\">", false, true),
+    ];
+    assert_eq!(
+        render_shared_templates(&templates, "r-dom").unwrap(),
+        concat!(
+            "import { template } from \"r-dom\";\n",
+            "export const tmpl_1 = /*#__PURE__*/ template(`<div class=\"row\">`);\n",
+            "export const tmpl_2 = /*#__PURE__*/ template(`<svg><circle r=\"5\">`, false, true);\n",
+            "export const tmpl_3 = /*#__PURE__*/ template(`<my-element label=\"x\">`, true, false);\n",
+        )
+    );
+}
+
+#[test]
+fn renders_empty_shared_templates_module() {
+    assert_eq!(
+        render_shared_templates(&[], "r-dom").unwrap(),
+        "import { template } from \"r-dom\";\n"
+    );
+}
+
+#[test]
+fn escapes_shared_templates_module_name() {
+    assert_eq!(
+        render_shared_templates(&[], "r-\"dom\"\u{2028}é").unwrap(),
+        "import { template } from \"r-\\\"dom\\\"\u{2028}é\";\n"
+    );
+}
+
+#[test]
+fn dedupes_repeated_shared_templates() {
+    let template = shared_template("tmpl_1", "<div>", false, false);
+    assert_eq!(
+        render_shared_templates(&[template.clone(), template], "r-dom").unwrap(),
+        concat!(
+            "import { template } from \"r-dom\";\n",
+            "export const tmpl_1 = /*#__PURE__*/ template(`<div>`);\n",
+        )
+    );
+}
+
+#[test]
+fn rejects_conflicting_shared_templates() {
+    let templates = [
+        shared_template("tmpl_1", "<div>", false, false),
+        shared_template("tmpl_1", "<span>", false, false),
+    ];
+    assert!(render_shared_templates(&templates, "r-dom").is_err());
+}
+
+#[test]
+fn reads_shared_templates_comment() {
+    let templates = read_shared_templates(
+        r#" @shared-templates [{"name":"tmpl_1","template":"<p>1 *\/ 2","isSvg":false,"isCe":true}] "#,
+    )
+    .unwrap();
+    assert_eq!(templates, [shared_template("tmpl_1", "<p>1 */ 2", false, true)]);
+}
+
+#[test]
+fn ignores_other_comments() {
+    assert_eq!(read_shared_templates("#__PURE__"), None);
+    assert_eq!(read_shared_templates(" @shared-templates not json "), None);
+}